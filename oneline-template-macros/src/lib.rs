@@ -0,0 +1,68 @@
+//! Procedural macros for `oneline-template`. Use them through feature `macros` of `oneline-template`.
+extern crate proc_macro;
+
+mod template_function;
+mod template_macro;
+mod oneline_template;
+
+use proc_macro::TokenStream;
+
+/// Creates function executor from rust function.
+///
+/// Generates unit struct named after function in `CamelCase` (or `struct_name`) that implements `FunctionExecutor`.
+/// First parameter of function is input value, other parameters are arguments declared within template.
+/// Types are converted using `FromValue`, `FromArgument` and `IntoFunctionResult`.
+///
+/// ```rust,ignore
+/// #[template_function(name = "uint:neg")]
+/// fn neg(value: u128) -> u128 {
+///     return !value;
+/// }
+///
+/// let template_builder = TemplateBuilder::new().with_function(Neg);
+/// ```
+#[proc_macro_attribute]
+pub fn template_function(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    return template_function::expand(attribute.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into();
+}
+
+/// Checks template syntax when crate is compiled and creates `LazyTemplate` with default functions.
+///
+/// Syntax error is reported as compile error.
+///
+/// ```rust,ignore
+/// let template = template!("dir/{self.id}.json");
+/// let path = template.serialize(&item)?;
+/// ```
+#[proc_macro]
+pub fn template(input: TokenStream) -> TokenStream {
+    return template_macro::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into();
+}
+
+/// Implements `OnelineTemplate` and `FieldValues` for structure using template from `#[template("...")]` attribute.
+///
+/// Template syntax and field names are checked when crate is compiled. Fields are converted using `ToValue`,
+/// so serialization is not used at runtime.
+///
+/// Function names and arguments are checked when template is first rendered: `try_render` returns
+/// `TemplateError::BuildError` and `render` panics if function is not found.
+///
+/// ```rust,ignore
+/// #[derive(OnelineTemplate)]
+/// #[template("dir/{self.id | uint:hex()}.json")]
+/// struct File {
+///     id: u32,
+/// }
+///
+/// assert_eq!(File {id: 255}.render(), "dir/ff.json");
+/// ```
+#[proc_macro_derive(OnelineTemplate, attributes(template))]
+pub fn derive_oneline_template(input: TokenStream) -> TokenStream {
+    return oneline_template::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into();
+}
@@ -0,0 +1,163 @@
+use oneline_template_syntax::field::Field;
+use oneline_template_syntax::field_path::FieldPath;
+use oneline_template_syntax::function_call_argument_token::FunctionCallArgumentToken;
+use oneline_template_syntax::function_call_token::FunctionCallToken;
+use oneline_template_syntax::syntax::Syntax;
+use oneline_template_syntax::token::Token;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{DeriveInput, Ident, Index, LitStr};
+
+fn parse_format(input: &DeriveInput) -> Result<LitStr, syn::Error> {
+    let attribute = input
+        .attrs
+        .iter()
+        .find(|attribute| {
+            return attribute.path().is_ident("template");
+        })
+        .ok_or_else(|| {
+            return syn::Error::new(Span::call_site(), "Attribute `#[template(\"...\")]` is required");
+        })?;
+    return attribute.parse_args::<LitStr>();
+}
+
+fn collect_argument_paths<'a>(function_calls: &'a [FunctionCallToken], paths: &mut Vec<&'a FieldPath>) {
+    for function_call in function_calls.iter() {
+        for argument in function_call.get_arguments().iter() {
+            match argument {
+                FunctionCallArgumentToken::Field(ref path) => {
+                    paths.push(path);
+                },
+                FunctionCallArgumentToken::Function(ref function_call) => {
+                    collect_argument_paths(std::slice::from_ref(function_call), paths);
+                },
+                _ => {},
+            }
+        }
+    }
+}
+
+fn collect_paths<'a>(syntax: &'a Syntax, format: &LitStr) -> Result<Vec<&'a FieldPath>, syn::Error> {
+    let mut paths = Vec::new();
+    for token in syntax.iter_tokens() {
+        match token {
+            Token::Text(..) => {},
+            Token::Template(ref template) => {
+                let template = template.get_field_read_token();
+                paths.push(template.get_path());
+                collect_argument_paths(template.get_function_calls(), &mut paths);
+            },
+            Token::UriExpression(..) => {
+                return Err(syn::Error::new(format.span(), "URI template expressions are not supported"));
+            },
+            Token::Include(ref include) => {
+                let message = format!("Template inclusion `{{> {}}}` is not supported", include.get_template_name().as_string_ref());
+                return Err(syn::Error::new(format.span(), message));
+            },
+        }
+    }
+    for path in paths.iter() {
+        if path.get_fields().is_empty() {
+            return Err(syn::Error::new(format.span(), "Template must read fields of structure, e.g. `{self.id}`"));
+        }
+    }
+    let mut unique_paths: Vec<&FieldPath> = Vec::new();
+    for path in paths {
+        let is_known = unique_paths
+            .iter()
+            .any(|unique_path| {
+                return unique_path.to_string() == path.to_string();
+            });
+        if !is_known {
+            unique_paths.push(path);
+        }
+    }
+    return Ok(unique_paths);
+}
+
+fn field_access(path: &FieldPath, span: Span) -> TokenStream {
+    let fields = path
+        .get_fields()
+        .iter()
+        .map(|field| {
+            return match field {
+                Field::Field(ref field_name) => {
+                    let field_name = field_name.as_str();
+                    let ident = syn::parse_str::<Ident>(field_name)
+                        .map(|ident| Ident::new(&ident.to_string(), span))
+                        .unwrap_or_else(|_| Ident::new_raw(field_name, span));
+                    quote!(#ident)
+                },
+                Field::Index(index) => {
+                    let index = Index {
+                        index: *index as u32,
+                        span,
+                    };
+                    quote!(#index)
+                },
+            };
+        });
+    return quote!(self #(.#fields)*);
+}
+
+fn path_segments(path: &FieldPath) -> Vec<String> {
+    return path
+        .get_fields()
+        .iter()
+        .map(|field| {
+            return match field {
+                Field::Field(ref field_name) => field_name.as_str().to_string(),
+                Field::Index(index) => index.to_string(),
+            };
+        })
+        .collect();
+}
+
+pub fn expand(input: TokenStream) -> Result<TokenStream, syn::Error> {
+    let input: DeriveInput = syn::parse2(input)?;
+    let format = parse_format(&input)?;
+    let syntax = format.value().parse::<Syntax>().map_err(|error| {
+        return syn::Error::new(format.span(), format!("Unable to parse template: {}", error));
+    })?;
+    let paths = collect_paths(&syntax, &format)?;
+    let segments: Vec<_> = paths
+        .iter()
+        .map(|path| path_segments(path))
+        .collect();
+    let accesses: Vec<_> = paths
+        .iter()
+        .map(|path| field_access(path, format.span()))
+        .collect();
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let function_executor = quote!(::oneline_template::function_executor);
+    let template = quote!(::oneline_template::template);
+    let expanded = quote! {
+        impl #impl_generics #template::FieldValues for #name #type_generics #where_clause {
+            fn field_value(&self, path: &[&str]) -> ::std::option::Option<#function_executor::Value> {
+                return match path {
+                    #(
+                        [#(#segments),*] => ::std::option::Option::Some(#function_executor::ToValue::to_value(&#accesses)),
+                    )*
+                    _ => ::std::option::Option::None,
+                };
+            }
+        }
+
+        impl #impl_generics #template::OnelineTemplate for #name #type_generics #where_clause {
+            fn template_format() -> &'static str {
+                return #format;
+            }
+
+            fn try_render(&self) -> ::std::result::Result<::std::string::String, #template::template_error::TemplateError> {
+                ::std::thread_local! {
+                    static TEMPLATE: #template::LazyTemplate = const { #template::LazyTemplate::new(#format) };
+                }
+                return TEMPLATE.with(|template| {
+                    return template.try_get()?.render(self);
+                });
+            }
+        }
+    };
+    return Ok(expanded);
+}
@@ -0,0 +1,152 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Expr, ExprLit, FnArg, Ident, ItemFn, Lit, LitStr, Meta, ReturnType, Token, Type};
+
+struct Attributes {
+    function_name: LitStr,
+    struct_name: Option<Ident>,
+}
+
+fn parse_attributes(attribute: TokenStream) -> Result<Attributes, syn::Error> {
+    let metas = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(attribute)?;
+    let mut function_name = None;
+    let mut struct_name = None;
+    for meta in metas.iter() {
+        let name_value = match meta {
+            Meta::NameValue(name_value) => name_value,
+            _ => {
+                return Err(syn::Error::new(meta.span(), "Expected `name = \"ns:name\"` or `struct_name = \"Name\"`"));
+            },
+        };
+        let value = match name_value.value {
+            Expr::Lit(ExprLit {lit: Lit::Str(ref value), ..}) => value.clone(),
+            _ => {
+                return Err(syn::Error::new(name_value.value.span(), "Expected string literal"));
+            },
+        };
+        if name_value.path.is_ident("name") {
+            if value.value().is_empty() {
+                return Err(syn::Error::new(value.span(), "Function name is empty"));
+            }
+            function_name = Some(value);
+        } else if name_value.path.is_ident("struct_name") {
+            struct_name = Some(value.parse::<Ident>()?);
+        } else {
+            return Err(syn::Error::new(name_value.path.span(), "Unknown attribute, expected `name` or `struct_name`"));
+        }
+    }
+    let function_name = function_name.ok_or_else(|| {
+        return syn::Error::new(Span::call_site(), "Attribute `name = \"ns:name\"` is required");
+    })?;
+    return Ok(Attributes {
+        function_name,
+        struct_name,
+    });
+}
+
+fn to_camel_case(ident: &Ident) -> Ident {
+    let name: String = ident
+        .to_string()
+        .trim_start_matches("r#")
+        .split('_')
+        .filter(|part| {
+            return !part.is_empty();
+        })
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().map(|first| first.to_uppercase().collect::<String>()).unwrap_or_default();
+            return first + chars.as_str();
+        })
+        .collect();
+    return Ident::new(&name, ident.span());
+}
+
+fn parameter_types(function: &ItemFn) -> Result<Vec<&Type>, syn::Error> {
+    let mut types = Vec::new();
+    for input in function.sig.inputs.iter() {
+        match input {
+            FnArg::Typed(pattern) => {
+                types.push(pattern.ty.as_ref());
+            },
+            FnArg::Receiver(receiver) => {
+                return Err(syn::Error::new(receiver.span(), "Template function can not have `self` parameter"));
+            },
+        }
+    }
+    return Ok(types);
+}
+
+pub fn expand(attribute: TokenStream, item: TokenStream) -> Result<TokenStream, syn::Error> {
+    let attributes = parse_attributes(attribute)?;
+    let function: ItemFn = syn::parse2(item)?;
+    if function.sig.asyncness.is_some() {
+        return Err(syn::Error::new(function.sig.asyncness.span(), "Template function can not be async"));
+    }
+    if !function.sig.generics.params.is_empty() {
+        return Err(syn::Error::new(function.sig.generics.span(), "Template function can not be generic"));
+    }
+    let types = parameter_types(&function)?;
+    let (input_type, argument_types) = match types.split_first() {
+        Some((input_type, argument_types)) => (*input_type, argument_types),
+        None => {
+            return Err(syn::Error::new(function.sig.span(), "Template function requires input parameter"));
+        },
+    };
+    let output_type = match function.sig.output {
+        ReturnType::Type(_, ref output_type) => output_type.as_ref().clone(),
+        ReturnType::Default => {
+            return Err(syn::Error::new(function.sig.span(), "Template function requires return type"));
+        },
+    };
+    let visibility = &function.vis;
+    let function_ident = &function.sig.ident;
+    let function_name = &attributes.function_name;
+    let struct_name = attributes
+        .struct_name
+        .unwrap_or_else(|| to_camel_case(function_ident));
+    let documentation = format!(" Function: `{}`", function_name.value());
+    let arguments_len = argument_types.len();
+    let argument_indexes = 0..arguments_len;
+    let krate = quote!(::oneline_template::function_executor);
+    let expanded = quote! {
+        #function
+
+        #[doc = #documentation]
+        #visibility struct #struct_name;
+
+        impl #krate::FunctionExecutor for #struct_name {
+            fn schema(&self) -> #krate::FunctionSchema {
+                let mut schema = #krate::FunctionSchema::new(#function_name);
+                let input_type = <#input_type as #krate::FromValue>::value_type();
+                if input_type != #krate::ValueType::Any {
+                    schema = schema.with_input_type(input_type);
+                }
+                let output_type = <#output_type as #krate::IntoFunctionResult>::output_type();
+                if output_type != #krate::ValueType::Any {
+                    schema = schema.with_output_type(output_type);
+                }
+                #(
+                    schema = schema.with_argument(<#argument_types as #krate::FromArgument>::argument());
+                )*
+                return schema;
+            }
+
+            fn call(&self, input: #krate::Value, arguments: &[#krate::Value]) -> ::std::result::Result<#krate::Value, #krate::FunctionError> {
+                if arguments.len() != #arguments_len {
+                    return Err(#krate::FunctionError::msg("Function called with wrong number of arguments"));
+                }
+                let result = #function_ident(
+                    <#input_type as #krate::FromValue>::from_value(input)?,
+                    #(
+                        <#argument_types as #krate::FromValue>::from_value(arguments[#argument_indexes].clone())?,
+                    )*
+                );
+                return #krate::IntoFunctionResult::into_function_result(result);
+            }
+        }
+    };
+    return Ok(expanded);
+}
@@ -0,0 +1,15 @@
+use oneline_template_syntax::syntax::Syntax;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+pub fn expand(input: TokenStream) -> Result<TokenStream, syn::Error> {
+    let format: LitStr = syn::parse2(input)?;
+    if let Err(error) = format.value().parse::<Syntax>() {
+        return Err(syn::Error::new(format.span(), format!("Unable to parse template: {}", error)));
+    }
+    let expanded = quote! {
+        ::oneline_template::template::LazyTemplate::new(#format)
+    };
+    return Ok(expanded);
+}
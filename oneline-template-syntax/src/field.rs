@@ -1,8 +1,8 @@
-use crate::template::syntax::syntax_parse_error::SyntaxParseError;
-use crate::template::syntax::field_name::FieldName;
+use crate::syntax_parse_error::SyntaxParseError;
+use crate::field_name::FieldName;
 use std::str::FromStr;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Field {
     Field(FieldName),
     Index(usize),
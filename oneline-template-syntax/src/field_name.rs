@@ -1,9 +1,13 @@
 
-use crate::template::syntax::syntax_parse_error::SyntaxParseError;
-#[derive(Debug)]
+use crate::syntax_parse_error::SyntaxParseError;
+#[derive(Debug, Clone)]
 pub struct FieldName(String);
 
 impl FieldName {
+    pub fn new(field_name: String) -> FieldName {
+        return FieldName(field_name);
+    }
+
     pub fn as_str(&self) -> &str {
         return self.0.as_str();
     }
@@ -1,14 +1,37 @@
-use crate::template::syntax::syntax_parse_error::SyntaxParseError;
-use crate::template::syntax::field::Field;
+use crate::syntax_parse_error::SyntaxParseError;
+use crate::field::Field;
+use crate::field_name::FieldName;
+use std::str::FromStr;
 use std::fmt;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FieldPath {
     path: Vec<Field>,
 }
 
 impl FieldPath {
     const DOT: char = '.';
+
+    pub fn new(path: Vec<Field>) -> FieldPath {
+        return FieldPath {
+            path,
+        }
+    }
+
+    /// Creates path from field names, numeric field name is index of sequence.
+    pub fn from_fields(fields: &[&str]) -> FieldPath {
+        let path = fields
+            .iter()
+            .map(|field| {
+                return match usize::from_str(field) {
+                    Ok(index) => Field::Index(index),
+                    Err(..) => Field::Field(FieldName::new(field.to_string())),
+                };
+            })
+            .collect();
+        return FieldPath::new(path);
+    }
+
     pub fn get_fields(&self) -> &[Field] {
         return &self.path;
     }
@@ -34,11 +57,11 @@ impl fmt::Display for FieldPath {
             if !is_first {
                 write!(f, ".")?;
             }
-            match field {
-                &Field::Index(index) => {
+            match *field {
+                Field::Index(index) => {
                     write!(f, "{}", index)?;
                 },
-                &Field::Field(ref field) => {
+                Field::Field(ref field) => {
                     write!(f, "{}", field.as_str())?;
                 },
             }
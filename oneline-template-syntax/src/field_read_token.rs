@@ -1,7 +1,7 @@
-use crate::template::syntax::field_path::FieldPath;
-use crate::template::syntax::function_call_token::FunctionCallToken;
+use crate::field_path::FieldPath;
+use crate::function_call_token::FunctionCallToken;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FieldReadToken {
     path: FieldPath,
     function_calls: Vec<FunctionCallToken>,
@@ -1,14 +1,20 @@
 use std::str::FromStr;
-use crate::template::syntax::unexpected_input_error::UnexpectedInputError;
-use crate::template::syntax::syntax_parse_error::SyntaxParseError;
-use crate::template::syntax::invalid_argument_template::InvalidArgumentTemplate;
+use crate::unexpected_input_error::UnexpectedInputError;
+use crate::syntax_parse_error::SyntaxParseError;
+use crate::invalid_argument_template::InvalidArgumentTemplate;
+use crate::field_path::FieldPath;
+use crate::function_call_token::FunctionCallToken;
+use std::fmt;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum FunctionCallArgumentToken {
     String(String),
     Bool(bool),
     UInt(u128),
     Int(i128),
+    Float(f64),
+    Field(FieldPath),
+    Function(FunctionCallToken),
 }
 
 impl FunctionCallArgumentToken {
@@ -18,18 +24,27 @@ impl FunctionCallArgumentToken {
     const BOOL_FALSE_TOKEN: &'static str = "false";
     const UINT_TOKEN: &'static str = "uint";
     const INT_TOKEN: &'static str = "int";
+    const FLOAT_TOKEN: &'static str = "float";
+    const SELF_TOKEN: &'static str = "self";
     pub fn parse(input: &str) -> Result<(FunctionCallArgumentToken, &str), SyntaxParseError> {
         let original_input = input;
         if input.starts_with(Self::STRING_TOKEN) {
             return Self::parse_string(input);
-        } else if input.starts_with(Self::BOOL_TRUE_TOKEN) {
-            let input = &input[Self::BOOL_TRUE_TOKEN.len()..];
+        } else if let Some(input) = input.strip_prefix(Self::BOOL_TRUE_TOKEN) {
             let result = FunctionCallArgumentToken::Bool(true);
             return Ok((result, input));
-        } else if input.starts_with(Self::BOOL_FALSE_TOKEN) {
-            let input = &input[Self::BOOL_FALSE_TOKEN.len()..];
+        } else if let Some(input) = input.strip_prefix(Self::BOOL_FALSE_TOKEN) {
             let result = FunctionCallArgumentToken::Bool(false);
             return Ok((result, input));
+        } else if Self::is_field(input) {
+            let input = &input[Self::SELF_TOKEN.len()..];
+            let (path, input) = FieldPath::parse(input)?;
+            let result = FunctionCallArgumentToken::Field(path);
+            return Ok((result, input));
+        } else if input.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+            let (function_call, input) = FunctionCallToken::parse(input)?;
+            let result = FunctionCallArgumentToken::Function(function_call);
+            return Ok((result, input));
         }
         let mut number = String::new();
         for c in input.chars() {
@@ -40,6 +55,9 @@ impl FunctionCallArgumentToken {
             if c.is_numeric() {
                 is_allowed = true;
             }
+            if c == '.' {
+                is_allowed = true;
+            }
             if is_allowed {
                 number.push(c);
             } else {
@@ -47,16 +65,18 @@ impl FunctionCallArgumentToken {
             }
         }
         let input = &input[number.len()..];
-        if input.starts_with(Self::UINT_TOKEN) {
-            let input = &input[Self::UINT_TOKEN.len()..];
+        if let Some(input) = input.strip_prefix(Self::UINT_TOKEN) {
             let number = u128::from_str(&number)?;
             let result = FunctionCallArgumentToken::UInt(number);
             return Ok((result, input));
-        } else if input.starts_with(Self::INT_TOKEN) {
-            let input = &input[Self::INT_TOKEN.len()..];
+        } else if let Some(input) = input.strip_prefix(Self::INT_TOKEN) {
             let number = i128::from_str(&number)?;
             let result = FunctionCallArgumentToken::Int(number);
             return Ok((result, input));
+        } else if let Some(input) = input.strip_prefix(Self::FLOAT_TOKEN) {
+            let number = f64::from_str(&number)?;
+            let result = FunctionCallArgumentToken::Float(number);
+            return Ok((result, input));
         }
         let text_length = original_input.len() - input.len();
         let invalid_argument_template = &original_input[0..text_length];
@@ -94,4 +114,44 @@ impl FunctionCallArgumentToken {
         }
         return Err(UnexpectedInputError::new(input).into());
     }
-}
\ No newline at end of file
+
+    fn is_field(input: &str) -> bool {
+        if !input.starts_with(Self::SELF_TOKEN) {
+            return false;
+        }
+        let input = &input[Self::SELF_TOKEN.len()..];
+        return !input.starts_with(|c: char| c.is_alphanumeric() || c == '_' || c == ':');
+    }
+}
+
+impl fmt::Display for FunctionCallArgumentToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FunctionCallArgumentToken::String(ref value) => {
+                write!(f, "'{}'", value.replace('\'', "\\'"))
+            },
+            FunctionCallArgumentToken::Bool(value) => {
+                write!(f, "{}", value)
+            },
+            FunctionCallArgumentToken::UInt(value) => {
+                write!(f, "{}uint", value)
+            },
+            FunctionCallArgumentToken::Int(value) => {
+                write!(f, "{}int", value)
+            },
+            FunctionCallArgumentToken::Float(value) => {
+                write!(f, "{}float", value)
+            },
+            FunctionCallArgumentToken::Field(ref path) => {
+                if path.get_fields().is_empty() {
+                    write!(f, "self")
+                } else {
+                    write!(f, "self.{}", path)
+                }
+            },
+            FunctionCallArgumentToken::Function(ref function_call) => {
+                write!(f, "{}", function_call)
+            },
+        }
+    }
+}
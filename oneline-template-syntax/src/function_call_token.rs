@@ -1,9 +1,10 @@
-use crate::template::syntax::function_call_argument_token::FunctionCallArgumentToken;
-use crate::template::syntax::function_name::FunctionName;
-use crate::template::syntax::syntax_parse_error::SyntaxParseError;
-use crate::template::syntax::expected_token_error::ExpectedTokenError;
+use crate::function_call_argument_token::FunctionCallArgumentToken;
+use crate::function_name::FunctionName;
+use crate::syntax_parse_error::SyntaxParseError;
+use crate::expected_token_error::ExpectedTokenError;
+use std::fmt;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FunctionCallToken {
     function_name: FunctionName,
     arguments: Vec<FunctionCallArgumentToken>,
@@ -55,4 +56,17 @@ impl FunctionCallToken {
         }
         return Ok((result, input));
     }
-}
\ No newline at end of file
+}
+
+impl fmt::Display for FunctionCallToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}(", self.function_name.as_string_ref())?;
+        for (index, argument) in self.arguments.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", argument)?;
+        }
+        write!(f, ")")
+    }
+}
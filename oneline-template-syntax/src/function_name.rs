@@ -1,6 +1,6 @@
-use crate::template::syntax::syntax_parse_error::SyntaxParseError;
+use crate::syntax_parse_error::SyntaxParseError;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FunctionName(String);
 
 impl FunctionName {
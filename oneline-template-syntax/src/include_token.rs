@@ -0,0 +1,50 @@
+use crate::syntax_parse_error::SyntaxParseError;
+use crate::template_name::TemplateName;
+use crate::expected_token_error::ExpectedTokenError;
+
+/// Inclusion of other template: `{> template_name}`.
+#[derive(Debug, Clone)]
+pub struct IncludeToken {
+    template_name: TemplateName,
+}
+
+impl IncludeToken {
+    const FIRST_FORMATTER_TOKEN: char = '{';
+    const LAST_FORMATTER_TOKEN: char = '}';
+    const INCLUDE_TOKEN: char = '>';
+
+    pub fn get_template_name(&self) -> &TemplateName {
+        return &self.template_name;
+    }
+
+    pub fn is_include(input: &str) -> bool {
+        if !input.starts_with(Self::FIRST_FORMATTER_TOKEN) {
+            return false;
+        }
+        let input = &input[Self::FIRST_FORMATTER_TOKEN.len_utf8()..];
+        return input.trim_start().starts_with(Self::INCLUDE_TOKEN);
+    }
+
+    pub fn parse(input: &str) -> Result<(Self, &str), SyntaxParseError> {
+        if !input.starts_with(Self::FIRST_FORMATTER_TOKEN) {
+            return Err(ExpectedTokenError::new(Self::FIRST_FORMATTER_TOKEN, input).into());
+        }
+        let input = &input[Self::FIRST_FORMATTER_TOKEN.len_utf8()..];
+        let input = input.trim_start();
+        if !input.starts_with(Self::INCLUDE_TOKEN) {
+            return Err(ExpectedTokenError::new(Self::INCLUDE_TOKEN, input).into());
+        }
+        let input = &input[Self::INCLUDE_TOKEN.len_utf8()..];
+        let input = input.trim_start();
+        let (template_name, input) = TemplateName::parse(input)?;
+        let input = input.trim_start();
+        if !input.starts_with(Self::LAST_FORMATTER_TOKEN) {
+            return Err(ExpectedTokenError::new(Self::LAST_FORMATTER_TOKEN, input).into());
+        }
+        let input = &input[Self::LAST_FORMATTER_TOKEN.len_utf8()..];
+        let result = IncludeToken {
+            template_name,
+        };
+        return Ok((result, input));
+    }
+}
@@ -1,3 +1,4 @@
+//! Template syntax parser shared by `oneline-template` and `oneline-template-macros`.
 pub mod syntax;
 pub mod syntax_parse_error;
 pub mod expected_token_error;
@@ -11,4 +12,11 @@ pub mod function_call_argument_token;
 pub mod field_name;
 pub mod function_name;
 pub mod field_path;
-pub mod invalid_argument_template;
\ No newline at end of file
+pub mod invalid_argument_template;
+pub mod uri_expression_token;
+pub mod uri_operator;
+pub mod uri_modifier;
+pub mod uri_variable;
+pub mod uri_encoding;
+pub mod include_token;
+pub mod template_name;
\ No newline at end of file
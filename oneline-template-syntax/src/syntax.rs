@@ -0,0 +1,125 @@
+use crate::token::Token;
+use crate::template_token::TemplateToken;
+use crate::syntax_parse_error::SyntaxParseError;
+use crate::uri_expression_token::UriExpressionToken;
+use crate::uri_encoding::uri_encode;
+use crate::include_token::IncludeToken;
+use crate::unexpected_input_error::UnexpectedInputError;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct Syntax {
+    tokens: Vec<Token>,
+}
+
+impl Syntax {
+    /// Creates syntax from tokens merging adjacent text tokens.
+    pub fn from_tokens(tokens: impl IntoIterator<Item=Token>) -> Syntax {
+        let mut result = Syntax {
+            tokens: Vec::new(),
+        };
+        for token in tokens {
+            match token {
+                Token::Text(text) => {
+                    if text.is_empty() {
+                        continue;
+                    }
+                    if let Some(Token::Text(last_text)) = result.tokens.last_mut() {
+                        *last_text += &text;
+                        continue;
+                    }
+                    result.tokens.push(Token::Text(text));
+                },
+                token => {
+                    result.tokens.push(token);
+                },
+            }
+        }
+        return result;
+    }
+
+    pub fn iter_tokens(&self) -> impl Iterator<Item=&Token> {
+        return self.tokens.iter();
+    }
+
+    /// Parses RFC 6570 URI template.
+    pub fn parse_uri_template(mut input: &str) -> Result<Syntax, SyntaxParseError> {
+        let mut result = Syntax {
+            tokens: Vec::new(),
+        };
+        loop {
+            let first_expression_token_index = UriExpressionToken::find_start(input);
+            match first_expression_token_index {
+                Some(first_expression_token_index) => {
+                    let text_token = &input[0..first_expression_token_index];
+                    if !text_token.is_empty() {
+                        result.tokens.push(Self::parse_uri_literal(text_token)?);
+                    }
+                    let (expression, input_remainder) = UriExpressionToken::parse(&input[first_expression_token_index..])?;
+                    input = input_remainder;
+                    result.tokens.push(Token::UriExpression(expression));
+                },
+                None => {
+                    if !input.is_empty() {
+                        result.tokens.push(Self::parse_uri_literal(input)?);
+                    }
+                    break;
+                },
+            }
+        }
+        if result.tokens.is_empty() {
+            return Err(SyntaxParseError::TemplateIsEmpty);
+        }
+        return Ok(result);
+    }
+
+    /// Parses literal text of URI template. Closing brace without expression is not allowed by RFC 6570.
+    fn parse_uri_literal(input: &str) -> Result<Token, SyntaxParseError> {
+        if let Some(index) = input.find('}') {
+            return Err(UnexpectedInputError::new(&input[index..]).into());
+        }
+        return Ok(Token::Text(uri_encode(input, true)));
+    }
+}
+
+
+impl FromStr for Syntax {
+    type Err = SyntaxParseError;
+
+    fn from_str(mut input: &str) -> Result<Self, Self::Err> {
+        let mut result = Syntax {
+            tokens: Vec::new(),
+        };
+        loop {
+            let first_template_token_index = TemplateToken::find_start(input);
+            match first_template_token_index {
+                Some(first_template_token_index) => {
+                    let text_token = &input[0..first_template_token_index];
+                    if !text_token.is_empty() {
+                        result.tokens.push(Token::Text(text_token.to_string()));
+                    }
+                    let template_input = &input[first_template_token_index..];
+                    if IncludeToken::is_include(template_input) {
+                        let (include, input_remainder) = IncludeToken::parse(template_input)?;
+                        input = input_remainder;
+                        result.tokens.push(Token::Include(include));
+                    } else {
+                        let (template, input_remainder) = TemplateToken::parse(template_input)?;
+                        input = input_remainder;
+                        result.tokens.push(Token::Template(template));
+                    }
+                },
+                None => {
+                    if !input.is_empty() {
+                        result.tokens.push(Token::Text(input.to_string()));
+                    }
+                    break;
+                },
+            }
+        }
+        if result.tokens.is_empty() {
+            return Err(SyntaxParseError::TemplateIsEmpty);
+        }
+        return Ok(result);
+    }
+}
\ No newline at end of file
@@ -1,8 +1,8 @@
-use crate::template::syntax::expected_token_error::ExpectedTokenError;
-use crate::template::syntax::invalid_argument_template::InvalidArgumentTemplate;
-use crate::template::syntax::unexpected_input_error::UnexpectedInputError;
+use crate::expected_token_error::ExpectedTokenError;
+use crate::invalid_argument_template::InvalidArgumentTemplate;
+use crate::unexpected_input_error::UnexpectedInputError;
 
-use std::num::ParseIntError;
+use std::num::{ParseIntError, ParseFloatError};
 use std::error::Error;
 use std::fmt;
 
@@ -11,6 +11,8 @@ use std::fmt;
 pub enum SyntaxParseError {
     /// Parsing integer error.
     ParseInt(ParseIntError),
+    /// Parsing float error.
+    ParseFloat(ParseFloatError),
     /// Function name contains invalid chars.
     WrongFunctionName,
     /// Field name contains invalid chars.
@@ -23,6 +25,10 @@ pub enum SyntaxParseError {
     TemplateIsEmpty,
     /// Unable to parse argument passed into function.
     InvalidArgumentTemplate(InvalidArgumentTemplate),
+    /// URI template variable contains invalid modifier.
+    WrongUriModifier,
+    /// Name of included template contains invalid chars.
+    WrongTemplateName,
 }
 
 impl fmt::Display for SyntaxParseError {
@@ -31,6 +37,9 @@ impl fmt::Display for SyntaxParseError {
             SyntaxParseError::ParseInt(ref error) => {
                 write!(f, "{}", error)
             },
+            SyntaxParseError::ParseFloat(ref error) => {
+                write!(f, "{}", error)
+            },
             SyntaxParseError::WrongFunctionName => {
                 write!(f, "Wrong function name")
             },
@@ -49,6 +58,12 @@ impl fmt::Display for SyntaxParseError {
             SyntaxParseError::InvalidArgumentTemplate(ref error) => {
                 write!(f, "{}", error)
             },
+            SyntaxParseError::WrongUriModifier => {
+                write!(f, "Wrong URI template variable modifier")
+            },
+            SyntaxParseError::WrongTemplateName => {
+                write!(f, "Wrong template name")
+            },
         }
     }
 }
@@ -59,6 +74,12 @@ impl From<ParseIntError> for SyntaxParseError {
     }
 }
 
+impl From<ParseFloatError> for SyntaxParseError {
+    fn from(error: ParseFloatError) -> Self {
+        return SyntaxParseError::ParseFloat(error);
+    }
+}
+
 impl From<ExpectedTokenError> for SyntaxParseError {
     fn from(error: ExpectedTokenError) -> Self {
         return SyntaxParseError::ExpectedToken(error);
@@ -83,6 +104,9 @@ impl Error for SyntaxParseError {
             SyntaxParseError::ParseInt(ref error) => {
                 Some(error)
             },
+            SyntaxParseError::ParseFloat(ref error) => {
+                Some(error)
+            },
             SyntaxParseError::WrongFunctionName => {
                 None
             },
@@ -101,6 +125,12 @@ impl Error for SyntaxParseError {
             SyntaxParseError::InvalidArgumentTemplate(ref error) => {
                 Some(error)
             },
+            SyntaxParseError::WrongUriModifier => {
+                None
+            },
+            SyntaxParseError::WrongTemplateName => {
+                None
+            },
         }
     }
 }
\ No newline at end of file
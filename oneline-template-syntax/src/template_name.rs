@@ -0,0 +1,34 @@
+use crate::syntax_parse_error::SyntaxParseError;
+
+#[derive(Debug, Clone)]
+pub struct TemplateName(String);
+
+impl TemplateName {
+    pub fn as_string_ref(&self) -> &String {
+        return &self.0;
+    }
+
+    pub fn parse(input: &str) -> Result<(Self, &str), SyntaxParseError> {
+        let mut template_name = String::new();
+        for c in input.chars() {
+            let mut is_allowed = false;
+            if c.is_alphanumeric() {
+                is_allowed = true;
+            }
+            if c == '_' || c == '-' || c == '.' || c == ':' {
+                is_allowed = true;
+            }
+            if is_allowed {
+                template_name.push(c);
+            } else {
+                break;
+            }
+        }
+        if template_name.is_empty() {
+            return Err(SyntaxParseError::WrongTemplateName);
+        }
+        let input = &input[template_name.len()..];
+        let template_name = TemplateName(template_name);
+        return Ok((template_name, input));
+    }
+}
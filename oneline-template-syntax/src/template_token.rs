@@ -1,11 +1,11 @@
-use crate::template::syntax::syntax_parse_error::SyntaxParseError;
-use crate::template::syntax::field_read_token::FieldReadToken;
-use crate::template::syntax::field_path::FieldPath;
-use crate::template::syntax::function_call_token::FunctionCallToken;
-use crate::template::syntax::expected_token_error::ExpectedTokenError;
-use crate::template::syntax::unexpected_input_error::UnexpectedInputError;
+use crate::syntax_parse_error::SyntaxParseError;
+use crate::field_read_token::FieldReadToken;
+use crate::field_path::FieldPath;
+use crate::function_call_token::FunctionCallToken;
+use crate::expected_token_error::ExpectedTokenError;
+use crate::unexpected_input_error::UnexpectedInputError;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TemplateToken(FieldReadToken);
 
 impl TemplateToken {
@@ -0,0 +1,11 @@
+use crate::template_token::TemplateToken;
+use crate::uri_expression_token::UriExpressionToken;
+use crate::include_token::IncludeToken;
+
+#[derive(Debug, Clone)]
+pub enum Token {
+    Text(String),
+    Template(TemplateToken),
+    UriExpression(UriExpressionToken),
+    Include(IncludeToken),
+}
\ No newline at end of file
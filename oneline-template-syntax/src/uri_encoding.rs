@@ -0,0 +1,44 @@
+const UNRESERVED_CHARS: &str = "-._~";
+const RESERVED_CHARS: &str = ":/?#[]@!$&'()*+,;=";
+const PERCENT_TOKEN: char = '%';
+
+fn is_unreserved(c: char) -> bool {
+    return c.is_ascii_alphanumeric() || UNRESERVED_CHARS.contains(c);
+}
+
+fn is_reserved(c: char) -> bool {
+    return RESERVED_CHARS.contains(c);
+}
+
+fn is_pct_encoded(input: &str) -> bool {
+    let mut chars = input.chars();
+    if chars.next() != Some(PERCENT_TOKEN) {
+        return false;
+    }
+    let first = chars.next().map(|c| c.is_ascii_hexdigit());
+    let second = chars.next().map(|c| c.is_ascii_hexdigit());
+    return first == Some(true) && second == Some(true);
+}
+
+/// Percent-encodes `input` as described in RFC 6570.
+///
+/// When `allow_reserved` is `true` reserved characters and percent-encoded triplets are kept as is.
+pub fn uri_encode(input: &str, allow_reserved: bool) -> String {
+    let mut result = String::with_capacity(input.len());
+    for (index, c) in input.char_indices() {
+        let is_allowed = if allow_reserved {
+            is_unreserved(c) || is_reserved(c) || is_pct_encoded(&input[index..])
+        } else {
+            is_unreserved(c)
+        };
+        if is_allowed {
+            result.push(c);
+            continue;
+        }
+        let mut buffer = [0; 4];
+        for byte in c.encode_utf8(&mut buffer).bytes() {
+            result += &format!("%{:02X}", byte);
+        }
+    }
+    return result;
+}
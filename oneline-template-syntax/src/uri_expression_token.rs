@@ -0,0 +1,57 @@
+use crate::syntax_parse_error::SyntaxParseError;
+use crate::expected_token_error::ExpectedTokenError;
+use crate::uri_operator::UriOperator;
+use crate::uri_variable::UriVariable;
+
+/// Expression of RFC 6570 URI template: `{?x,y*}`.
+#[derive(Debug, Clone)]
+pub struct UriExpressionToken {
+    operator: UriOperator,
+    variables: Vec<UriVariable>,
+}
+
+impl UriExpressionToken {
+    const FIRST_EXPRESSION_TOKEN: char = '{';
+    const LAST_EXPRESSION_TOKEN: char = '}';
+    const COMMA_TOKEN: char = ',';
+
+    pub fn get_operator(&self) -> UriOperator {
+        return self.operator;
+    }
+
+    pub fn get_variables(&self) -> &[UriVariable] {
+        return &self.variables;
+    }
+
+    pub fn find_start(input: &str) -> Option<usize> {
+        return input.find(Self::FIRST_EXPRESSION_TOKEN);
+    }
+
+    pub fn parse(input: &str) -> Result<(Self, &str), SyntaxParseError> {
+        if !input.starts_with(Self::FIRST_EXPRESSION_TOKEN) {
+            return Err(ExpectedTokenError::new(Self::FIRST_EXPRESSION_TOKEN, input).into());
+        }
+        let input = &input[Self::FIRST_EXPRESSION_TOKEN.len_utf8()..];
+        let (operator, mut input) = UriOperator::parse(input);
+        let mut variables = Vec::new();
+        loop {
+            let (variable, input_remainder) = UriVariable::parse(input)?;
+            variables.push(variable);
+            input = input_remainder;
+            if input.starts_with(Self::COMMA_TOKEN) {
+                input = &input[Self::COMMA_TOKEN.len_utf8()..];
+                continue;
+            }
+            if input.starts_with(Self::LAST_EXPRESSION_TOKEN) {
+                input = &input[Self::LAST_EXPRESSION_TOKEN.len_utf8()..];
+                break;
+            }
+            return Err(ExpectedTokenError::new(Self::LAST_EXPRESSION_TOKEN, input).into());
+        }
+        let result = UriExpressionToken {
+            operator,
+            variables,
+        };
+        return Ok((result, input));
+    }
+}
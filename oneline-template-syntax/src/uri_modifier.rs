@@ -0,0 +1,10 @@
+/// Value modifier of RFC 6570 URI template variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UriModifier {
+    /// Variable is expanded as is.
+    None,
+    /// `{var*}`: list and map items are expanded as separate variables.
+    Explode,
+    /// `{var:3}`: only first chars of string value are expanded.
+    Prefix(usize),
+}
@@ -0,0 +1,85 @@
+/// Expression operator of RFC 6570 URI template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UriOperator {
+    /// `{var}`
+    Simple,
+    /// `{+var}`
+    Reserved,
+    /// `{#var}`
+    Fragment,
+    /// `{.var}`
+    Label,
+    /// `{/var}`
+    PathSegment,
+    /// `{;var}`
+    PathParameter,
+    /// `{?var}`
+    Query,
+    /// `{&var}`
+    QueryContinuation,
+}
+
+impl UriOperator {
+    pub fn parse(input: &str) -> (UriOperator, &str) {
+        let operator = match input.chars().next() {
+            Some('+') => UriOperator::Reserved,
+            Some('#') => UriOperator::Fragment,
+            Some('.') => UriOperator::Label,
+            Some('/') => UriOperator::PathSegment,
+            Some(';') => UriOperator::PathParameter,
+            Some('?') => UriOperator::Query,
+            Some('&') => UriOperator::QueryContinuation,
+            _ => {
+                return (UriOperator::Simple, input);
+            },
+        };
+        return (operator, &input[1..]);
+    }
+
+    /// String that is prepended to the expansion when at least one variable is defined.
+    pub fn get_first(&self) -> &'static str {
+        match self {
+            UriOperator::Simple => "",
+            UriOperator::Reserved => "",
+            UriOperator::Fragment => "#",
+            UriOperator::Label => ".",
+            UriOperator::PathSegment => "/",
+            UriOperator::PathParameter => ";",
+            UriOperator::Query => "?",
+            UriOperator::QueryContinuation => "&",
+        }
+    }
+
+    /// Separator between expanded variables.
+    pub fn get_separator(&self) -> &'static str {
+        match self {
+            UriOperator::Simple => ",",
+            UriOperator::Reserved => ",",
+            UriOperator::Fragment => ",",
+            UriOperator::Label => ".",
+            UriOperator::PathSegment => "/",
+            UriOperator::PathParameter => ";",
+            UriOperator::Query => "&",
+            UriOperator::QueryContinuation => "&",
+        }
+    }
+
+    /// Returns `true` when expansion is written as `name=value` pairs.
+    pub fn is_named(&self) -> bool {
+        return matches!(self, UriOperator::PathParameter | UriOperator::Query | UriOperator::QueryContinuation);
+    }
+
+    /// String that follows the name of variable with empty value.
+    pub fn get_if_empty(&self) -> &'static str {
+        match self {
+            UriOperator::Query => "=",
+            UriOperator::QueryContinuation => "=",
+            _ => "",
+        }
+    }
+
+    /// Returns `true` when reserved characters are passed without encoding.
+    pub fn is_reserved_allowed(&self) -> bool {
+        return matches!(self, UriOperator::Reserved | UriOperator::Fragment);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::syntax_parse_error::SyntaxParseError;
+use crate::uri_modifier::UriModifier;
+use crate::field_path::FieldPath;
+use crate::field::Field;
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct UriVariable {
+    name: String,
+    path: FieldPath,
+    modifier: UriModifier,
+}
+
+impl UriVariable {
+    const EXPLODE_TOKEN: char = '*';
+    const PREFIX_TOKEN: char = ':';
+    const DOT: char = '.';
+    const MAX_PREFIX_LENGTH: usize = 9999;
+
+    pub fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    pub fn get_path(&self) -> &FieldPath {
+        return &self.path;
+    }
+
+    pub fn get_modifier(&self) -> UriModifier {
+        return self.modifier;
+    }
+
+    pub fn parse(input: &str) -> Result<(UriVariable, &str), SyntaxParseError> {
+        let mut name = String::new();
+        for c in input.chars() {
+            if c.is_ascii_alphanumeric() || c == '_' || c == Self::DOT {
+                name.push(c);
+            } else {
+                break;
+            }
+        }
+        let mut input = &input[name.len()..];
+        let mut fields = Vec::new();
+        for segment in name.split(Self::DOT) {
+            let (field, remainder) = Field::parse(segment)?;
+            if !remainder.is_empty() {
+                return Err(SyntaxParseError::WrongFieldName);
+            }
+            fields.push(field);
+        }
+        let path = FieldPath::new(fields);
+        let mut modifier = UriModifier::None;
+        if input.starts_with(Self::EXPLODE_TOKEN) {
+            input = &input[Self::EXPLODE_TOKEN.len_utf8()..];
+            modifier = UriModifier::Explode;
+        } else if input.starts_with(Self::PREFIX_TOKEN) {
+            input = &input[Self::PREFIX_TOKEN.len_utf8()..];
+            let length: String = input
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect();
+            input = &input[length.len()..];
+            let length = usize::from_str(&length)
+                .map_err(|_| {
+                    return SyntaxParseError::WrongUriModifier;
+                })?;
+            if length == 0 || length > Self::MAX_PREFIX_LENGTH {
+                return Err(SyntaxParseError::WrongUriModifier);
+            }
+            modifier = UriModifier::Prefix(length);
+        }
+        let variable = UriVariable {
+            name,
+            path,
+            modifier,
+        };
+        return Ok((variable, input));
+    }
+}
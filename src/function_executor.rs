@@ -1,12 +1,43 @@
+#[allow(clippy::module_inception)]
 mod function_executor;
 pub use self::function_executor::FunctionExecutor;
 mod value;
 pub use self::value::Value;
 mod function_schema;
 pub use self::function_schema::FunctionSchema;
+mod function_example;
+pub use self::function_example::FunctionExample;
+mod function_catalogue;
+pub use self::function_catalogue::FunctionCatalogue;
 mod function_argument;
 pub use self::function_argument::FunctionArgument;
 mod function_argument_type;
 pub (crate) use self::function_argument_type::FunctionArgumentType;
+mod output_shape;
+pub use self::output_shape::OutputShape;
 mod function_error;
-pub use self::function_error::FunctionError;
\ No newline at end of file
+pub use self::function_error::FunctionError;
+mod function_context;
+pub use self::function_context::FunctionContext;
+mod function_state;
+pub use self::function_state::FunctionState;
+mod value_type;
+pub use self::value_type::ValueType;
+mod renamed;
+pub use self::renamed::Renamed;
+mod function_module;
+pub use self::function_module::FunctionModule;
+mod from_value;
+pub use self::from_value::FromValue;
+mod from_argument;
+pub use self::from_argument::FromArgument;
+mod into_value;
+pub use self::into_value::IntoValue;
+mod to_value;
+pub use self::to_value::ToValue;
+mod into_function_result;
+pub use self::into_function_result::IntoFunctionResult;
+mod into_function;
+pub use self::into_function::IntoFunction;
+mod fn_executor;
+pub use self::fn_executor::FnExecutor;
@@ -0,0 +1,83 @@
+use crate::function_executor::function_executor::FunctionExecutor;
+use crate::function_executor::function_schema::FunctionSchema;
+use crate::function_executor::function_argument::FunctionArgument;
+use crate::function_executor::function_error::FunctionError;
+use crate::function_executor::into_function::IntoFunction;
+use crate::function_executor::value::Value;
+use crate::function_executor::value_type::ValueType;
+
+type Function = Box<dyn Fn(Value, &[Value]) -> Result<Value, FunctionError>>;
+
+/// Function executor created from rust closure or function pointer.
+///
+/// Schema is derived from types of closure: first parameter is input value, other parameters are arguments.
+///
+/// ```rust
+/// use oneline_template::function_executor::FnExecutor;
+///
+/// let function_executor = FnExecutor::new("string:repeat", |value: String, count: usize| -> String {
+///     return value.repeat(count);
+/// });
+/// ```
+pub struct FnExecutor {
+    function_name: String,
+    input_type: ValueType,
+    output_type: ValueType,
+    arguments: Vec<fn() -> FunctionArgument>,
+    function: Function,
+}
+
+impl FnExecutor {
+    /// Creates function executor with name `function_name`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `function_name` is empty.
+    pub fn new<F, Args>(function_name: impl Into<String>, function: F) -> FnExecutor
+        where
+            F: IntoFunction<Args>,
+    {
+        let function_name = function_name.into();
+        if function_name.is_empty() {
+            panic!("Passed function name is empty");
+        }
+        return function.into_function(function_name);
+    }
+
+    pub (crate) fn from_parts(
+        function_name: String,
+        input_type: ValueType,
+        output_type: ValueType,
+        arguments: Vec<fn() -> FunctionArgument>,
+        function: Function,
+    ) -> FnExecutor {
+        return FnExecutor {
+            function_name,
+            input_type,
+            output_type,
+            arguments,
+            function,
+        }
+    }
+}
+
+impl FunctionExecutor for FnExecutor {
+    fn schema(&self) -> FunctionSchema {
+        let mut schema = FunctionSchema::new(self.function_name.clone());
+        // Function accepting `Value` checks type of input value by itself.
+        if self.input_type != ValueType::Any {
+            schema = schema.with_input_type(self.input_type.clone());
+        }
+        if self.output_type != ValueType::Any {
+            schema = schema.with_output_type(self.output_type.clone());
+        }
+        for argument in self.arguments.iter() {
+            schema = schema.with_argument(argument());
+        }
+        return schema;
+    }
+
+    fn call(&self, input: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        return (self.function)(input, arguments);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::function_executor::from_value::FromValue;
+use crate::function_executor::function_argument::FunctionArgument;
+
+/// Rust type that can be passed as argument within template. Used by `TemplateBuilder::with_fn`.
+pub trait FromArgument: FromValue {
+    /// Returns schema of argument.
+    fn argument() -> FunctionArgument;
+}
+
+impl FromArgument for String {
+    fn argument() -> FunctionArgument {
+        return FunctionArgument::string();
+    }
+}
+
+impl FromArgument for bool {
+    fn argument() -> FunctionArgument {
+        return FunctionArgument::bool();
+    }
+}
+
+impl FromArgument for f64 {
+    fn argument() -> FunctionArgument {
+        return FunctionArgument::float();
+    }
+}
+
+impl FromArgument for f32 {
+    fn argument() -> FunctionArgument {
+        return FunctionArgument::float();
+    }
+}
+
+macro_rules! impl_from_argument_for_integer {
+    ($argument: ident, $($integer: ty),*) => {
+        $(
+            impl FromArgument for $integer {
+                fn argument() -> FunctionArgument {
+                    return FunctionArgument::$argument();
+                }
+            }
+        )*
+    };
+}
+
+impl_from_argument_for_integer!(uint, u8, u16, u32, u64, u128, usize);
+impl_from_argument_for_integer!(int, i8, i16, i32, i64, i128, isize);
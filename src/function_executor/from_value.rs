@@ -0,0 +1,127 @@
+use crate::function_executor::value::Value;
+use crate::function_executor::value_type::ValueType;
+use crate::function_executor::function_error::FunctionError;
+use std::convert::TryFrom;
+
+/// Converts `Value` into rust type. Used for input values and arguments of functions registered by `TemplateBuilder::with_fn`.
+pub trait FromValue: Sized {
+    /// Returns type of accepted value.
+    fn value_type() -> ValueType;
+    /// Converts value into rust type.
+    fn from_value(value: Value) -> Result<Self, FunctionError>;
+}
+
+impl FromValue for Value {
+    fn value_type() -> ValueType {
+        return ValueType::Any;
+    }
+
+    fn from_value(value: Value) -> Result<Self, FunctionError> {
+        return Ok(value);
+    }
+}
+
+impl FromValue for String {
+    fn value_type() -> ValueType {
+        return ValueType::String;
+    }
+
+    fn from_value(value: Value) -> Result<Self, FunctionError> {
+        return value.into_string();
+    }
+}
+
+impl FromValue for bool {
+    fn value_type() -> ValueType {
+        return ValueType::Bool;
+    }
+
+    fn from_value(value: Value) -> Result<Self, FunctionError> {
+        return value.into_bool();
+    }
+}
+
+impl FromValue for char {
+    fn value_type() -> ValueType {
+        return ValueType::Char;
+    }
+
+    fn from_value(value: Value) -> Result<Self, FunctionError> {
+        return Ok(*value.as_char()?);
+    }
+}
+
+impl FromValue for f64 {
+    fn value_type() -> ValueType {
+        return ValueType::Float;
+    }
+
+    fn from_value(value: Value) -> Result<Self, FunctionError> {
+        return value.floato_float();
+    }
+}
+
+impl FromValue for f32 {
+    fn value_type() -> ValueType {
+        return ValueType::Float;
+    }
+
+    fn from_value(value: Value) -> Result<Self, FunctionError> {
+        return Ok(value.floato_float()? as f32);
+    }
+}
+
+impl <T>FromValue for Option<T>
+    where
+        T: FromValue,
+{
+    fn value_type() -> ValueType {
+        return ValueType::option(T::value_type());
+    }
+
+    fn from_value(value: Value) -> Result<Self, FunctionError> {
+        return value
+            .into_option()?
+            .map(T::from_value)
+            .transpose();
+    }
+}
+
+impl <T>FromValue for Vec<T>
+    where
+        T: FromValue,
+{
+    fn value_type() -> ValueType {
+        return ValueType::seq(T::value_type());
+    }
+
+    fn from_value(value: Value) -> Result<Self, FunctionError> {
+        return value
+            .into_seq()?
+            .into_iter()
+            .map(T::from_value)
+            .collect();
+    }
+}
+
+macro_rules! impl_from_value_for_integer {
+    ($value_type: ident, $into: ident, $name: literal, $($integer: ty),*) => {
+        $(
+            impl FromValue for $integer {
+                fn value_type() -> ValueType {
+                    return ValueType::$value_type;
+                }
+
+                fn from_value(value: Value) -> Result<Self, FunctionError> {
+                    let value = value.$into()?;
+                    return <$integer>::try_from(value).map_err(|_| {
+                        return FunctionError::msg(format!("Unable to convert {} {} into {}", $name, value, stringify!($integer)));
+                    });
+                }
+            }
+        )*
+    };
+}
+
+impl_from_value_for_integer!(UInt, into_uint, "uint", u8, u16, u32, u64, u128, usize);
+impl_from_value_for_integer!(Int, into_int, "int", i8, i16, i32, i64, i128, isize);
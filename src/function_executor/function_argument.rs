@@ -1,9 +1,12 @@
 use crate::function_executor::function_argument_type::FunctionArgumentType;
+use serde::ser::{Serialize, Serializer, SerializeStruct};
 
 /// Information about function argument schema.
 #[derive(Debug)]
 pub struct FunctionArgument {
     argument_type: FunctionArgumentType,
+    name: Option<String>,
+    description: Option<String>,
 }
 
 impl FunctionArgument {
@@ -11,6 +14,8 @@ impl FunctionArgument {
     pub fn string() -> FunctionArgument {
         return FunctionArgument {
             argument_type: FunctionArgumentType::String,
+            name: None,
+            description: None,
         }
     }
 
@@ -18,6 +23,8 @@ impl FunctionArgument {
     pub fn bool() -> FunctionArgument {
         return FunctionArgument {
             argument_type: FunctionArgumentType::Bool,
+            name: None,
+            description: None,
         }
     }
 
@@ -25,6 +32,8 @@ impl FunctionArgument {
     pub fn uint() -> FunctionArgument {
         return FunctionArgument {
             argument_type: FunctionArgumentType::UInt,
+            name: None,
+            description: None,
         }
     }
     
@@ -32,10 +41,90 @@ impl FunctionArgument {
     pub fn int() -> FunctionArgument {
         return FunctionArgument {
             argument_type: FunctionArgumentType::Int,
+            name: None,
+            description: None,
         }
     }
 
+    /// Creates function argument schema with float type.
+    pub fn float() -> FunctionArgument {
+        return FunctionArgument {
+            argument_type: FunctionArgumentType::Float,
+            name: None,
+            description: None,
+        }
+    }
+
+    /// Creates function argument schema with field: `self.field`.
+    ///
+    /// Function receives value of field or empty option when field is missing.
+    pub fn field() -> FunctionArgument {
+        return FunctionArgument {
+            argument_type: FunctionArgumentType::Field,
+            name: None,
+            description: None,
+        }
+    }
+
+    /// Creates function argument schema with function call: `uint:hex()`.
+    ///
+    /// Function receives text of function call and executes it using `FunctionContext::call_argument`.
+    pub fn function() -> FunctionArgument {
+        return FunctionArgument {
+            argument_type: FunctionArgumentType::Function,
+            name: None,
+            description: None,
+        }
+    }
+
+    /// Sets argument name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        return self;
+    }
+
+    /// Sets argument description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        return self;
+    }
+
+    /// Returns argument name.
+    pub fn get_name(&self) -> Option<&str> {
+        return self.name.as_deref();
+    }
+
+    /// Returns argument description.
+    pub fn get_description(&self) -> Option<&str> {
+        return self.description.as_deref();
+    }
+
+    /// Returns name of argument type: `string`, `bool`, `uint`, `int`, `float`, `field` or `function`.
+    pub fn get_type_name(&self) -> &'static str {
+        return match self.argument_type {
+            FunctionArgumentType::String => "string",
+            FunctionArgumentType::Bool => "bool",
+            FunctionArgumentType::UInt => "uint",
+            FunctionArgumentType::Int => "int",
+            FunctionArgumentType::Float => "float",
+            FunctionArgumentType::Field => "field",
+            FunctionArgumentType::Function => "function",
+        };
+    }
+
     pub (crate) fn get_type(&self) -> &FunctionArgumentType {
         return &self.argument_type;
     }
-}
\ No newline at end of file
+}
+impl Serialize for FunctionArgument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer
+    {
+        let mut state = serializer.serialize_struct("FunctionArgument", 3)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("type", self.get_type_name())?;
+        state.serialize_field("description", &self.description)?;
+        return state.end();
+    }
+}
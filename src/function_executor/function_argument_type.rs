@@ -1,7 +1,10 @@
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FunctionArgumentType {
     String,
     Bool,
     UInt,
     Int,
-}
\ No newline at end of file
+    Float,
+    Field,
+    Function,
+}
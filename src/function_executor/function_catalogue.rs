@@ -0,0 +1,106 @@
+use crate::function_executor::function_schema::FunctionSchema;
+use serde::ser::{Serialize, Serializer};
+use std::fmt::Write;
+
+/// Schemas of functions registered in template builder: `TemplateBuilder::new().functions()`.
+///
+/// Schemas are sorted by function name, overloads are kept in registration order.
+#[derive(Debug)]
+pub struct FunctionCatalogue {
+    schemas: Vec<FunctionSchema>,
+}
+
+impl FunctionCatalogue {
+    /// Creates catalogue from function schemas.
+    pub fn new(mut schemas: Vec<FunctionSchema>) -> FunctionCatalogue {
+        schemas.sort_by(|a, b| a.get_function_name().cmp(b.get_function_name()));
+        return FunctionCatalogue {
+            schemas,
+        }
+    }
+
+    /// Returns function schemas.
+    pub fn iter(&self) -> impl Iterator<Item=&FunctionSchema> {
+        return self.schemas.iter();
+    }
+
+    /// Returns schemas of function with passed name, one schema per overload.
+    pub fn get(&self, function_name: &str) -> Vec<&FunctionSchema> {
+        return self
+            .schemas
+            .iter()
+            .filter(|schema| schema.get_function_name() == function_name)
+            .collect();
+    }
+
+    /// Returns count of function schemas.
+    pub fn len(&self) -> usize {
+        return self.schemas.len();
+    }
+
+    /// Checks whether catalogue is empty.
+    pub fn is_empty(&self) -> bool {
+        return self.schemas.is_empty();
+    }
+
+    /// Returns catalogue as JSON array of function schemas.
+    pub fn to_json(&self) -> String {
+        return serde_json::to_string_pretty(self)
+            .expect("Function catalogue is serializable into JSON");
+    }
+
+    /// Returns catalogue as Markdown: section per function with arguments table and examples.
+    pub fn to_markdown(&self) -> String {
+        let mut result = String::new();
+        for schema in self.schemas.iter() {
+            if !result.is_empty() {
+                result.push('\n');
+            }
+            let _ = writeln!(result, "## `{}`\n", schema.get_function_name());
+            if let Some(description) = schema.get_description() {
+                let _ = writeln!(result, "{}\n", description);
+            }
+            if let Some(input_type) = schema.get_input_type() {
+                let _ = writeln!(result, "Input: `{}`\n", input_type);
+            }
+            if let Some(output_type) = schema.get_output_type() {
+                let _ = writeln!(result, "Output: `{}`\n", output_type);
+            }
+            if !schema.get_arguments().is_empty() {
+                result += "| Argument | Type | Description |\n";
+                result += "|---|---|---|\n";
+                for (index, argument) in schema.get_arguments().iter().enumerate() {
+                    let name = match argument.get_name() {
+                        Some(name) => format!("`{}`", name),
+                        None => format!("{}", index),
+                    };
+                    let description = argument
+                        .get_description()
+                        .unwrap_or("")
+                        .replace('|', "\\|");
+                    let _ = writeln!(result, "| {} | `{}` | {} |", name, argument.get_type_name(), description);
+                }
+                result.push('\n');
+            }
+            if !schema.get_examples().is_empty() {
+                result += "Examples:\n\n";
+                for example in schema.get_examples() {
+                    let input = serde_json::to_string(example.get_input())
+                        .expect("Value is serializable into JSON");
+                    let _ = writeln!(result, "* `{}` formats `{}` as `{}`", example.get_template(), input, example.get_output());
+                }
+                result.push('\n');
+            }
+        }
+        return result;
+    }
+}
+
+impl Serialize for FunctionCatalogue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer
+    {
+        return self.schemas.serialize(serializer);
+    }
+}
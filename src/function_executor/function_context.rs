@@ -0,0 +1,85 @@
+use crate::function_executor::function_error::FunctionError;
+use crate::function_executor::value::Value;
+use crate::function_executor::function_state::FunctionState;
+use std::collections::HashMap;
+use std::cell::{RefCell, RefMut};
+
+type FieldReader<'a> = dyn Fn(&[&str]) -> Result<Option<Value>, FunctionError> + 'a;
+
+/// Context of function call. Gives access to functions passed as arguments, fields of templated value,
+/// context variables, path of field read by placeholder and mutable state of stateful functions.
+pub struct FunctionContext<'a> {
+    argument_caller: &'a dyn Fn(usize, Value) -> Result<Value, FunctionError>,
+    field_reader: &'a FieldReader<'a>,
+    variables: &'a HashMap<String, Value>,
+    path: String,
+    placeholder_index: usize,
+    render_state: &'a RefCell<FunctionState>,
+    batch_state: &'a RefCell<FunctionState>,
+}
+
+impl <'a>FunctionContext<'a> {
+    pub (crate) fn new(
+        argument_caller: &'a dyn Fn(usize, Value) -> Result<Value, FunctionError>,
+        field_reader: &'a FieldReader<'a>,
+        variables: &'a HashMap<String, Value>,
+        path: String,
+        placeholder_index: usize,
+        render_state: &'a RefCell<FunctionState>,
+        batch_state: &'a RefCell<FunctionState>,
+    ) -> FunctionContext<'a> {
+        return FunctionContext {
+            argument_caller,
+            field_reader,
+            variables,
+            path,
+            placeholder_index,
+            render_state,
+            batch_state,
+        }
+    }
+
+    /// Calls function passed as argument with index `argument_index`: `option:map(uint:hex())`.
+    pub fn call_argument(&self, argument_index: usize, input: Value) -> Result<Value, FunctionError> {
+        return (self.argument_caller)(argument_index, input);
+    }
+
+    /// Reads field of templated value: `read_field(&["tenant", "id"])` reads `self.tenant.id`.
+    ///
+    /// Returns `None` when field is missing or unit, empty option is returned as `Value::Option(None)`.
+    /// Numeric segment is read as index of sequence.
+    pub fn read_field(&self, path: &[&str]) -> Result<Option<Value>, FunctionError> {
+        return (self.field_reader)(path);
+    }
+
+    /// Returns context variable passed by `Template::serialize_with_context`. See `TemplateContext`.
+    pub fn get_variable(&self, name: &str) -> Option<&Value> {
+        return self.variables.get(name);
+    }
+
+    /// Returns path of field read by placeholder: `{self.user.name | ...}` gives `user.name`, `{self | ...}` gives empty string.
+    pub fn get_path(&self) -> &str {
+        return &self.path;
+    }
+
+    /// Returns index of placeholder within template starting from `0`.
+    ///
+    /// Stateful functions key their state by placeholder index, so every placeholder has its own state.
+    pub fn get_placeholder_index(&self) -> usize {
+        return self.placeholder_index;
+    }
+
+    /// Returns state shared by placeholders while one value is templated.
+    ///
+    /// State must be released before calling `FunctionContext::call_argument`.
+    pub fn get_render_state(&self) -> RefMut<'_, FunctionState> {
+        return self.render_state.borrow_mut();
+    }
+
+    /// Returns state shared by values of `Template::render_batch`. Single templated value is batch of one value.
+    ///
+    /// State must be released before calling `FunctionContext::call_argument`.
+    pub fn get_batch_state(&self) -> RefMut<'_, FunctionState> {
+        return self.batch_state.borrow_mut();
+    }
+}
@@ -0,0 +1,67 @@
+use crate::function_executor::into_value::IntoValue;
+use crate::function_executor::value::Value;
+use serde::ser::{Serialize, Serializer, SerializeStruct};
+
+/// Example of function call: `{self | string:substr(1uint, 3uint)}` formats `"hello"` as `ell`.
+#[derive(Debug)]
+pub struct FunctionExample {
+    input: Value,
+    field: String,
+    function_call: String,
+    output: String,
+}
+
+impl FunctionExample {
+    /// Creates example where `input` piped into `function_call` gives `output`.
+    ///
+    /// `FunctionExample::new("hello", "string:substr(1uint, 3uint)", "ell")`
+    pub fn new(input: impl IntoValue, function_call: impl Into<String>, output: impl Into<String>) -> FunctionExample {
+        return FunctionExample {
+            input: input.into_value(),
+            field: "self".to_string(),
+            function_call: function_call.into(),
+            output: output.into(),
+        }
+    }
+
+    /// Sets field piped into function call instead of `self`: `with_field("self.0")`.
+    ///
+    /// Used by functions reading other fields: `{self.0 | option:or(self.1)}`.
+    pub fn with_field(mut self, field: impl Into<String>) -> Self {
+        self.field = field.into();
+        return self;
+    }
+
+    /// Returns input value.
+    pub fn get_input(&self) -> &Value {
+        return &self.input;
+    }
+
+    /// Returns function call with arguments: `string:substr(1uint, 3uint)`.
+    pub fn get_function_call(&self) -> &str {
+        return &self.function_call;
+    }
+
+    /// Returns template of example: `{self | string:substr(1uint, 3uint)}`.
+    pub fn get_template(&self) -> String {
+        return format!("{{{} | {}}}", self.field, self.function_call);
+    }
+
+    /// Returns formatted string.
+    pub fn get_output(&self) -> &str {
+        return &self.output;
+    }
+}
+
+impl Serialize for FunctionExample {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer
+    {
+        let mut state = serializer.serialize_struct("FunctionExample", 3)?;
+        state.serialize_field("input", &self.input)?;
+        state.serialize_field("template", &self.get_template())?;
+        state.serialize_field("output", &self.output)?;
+        return state.end();
+    }
+}
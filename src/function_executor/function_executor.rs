@@ -1,6 +1,8 @@
 use crate::function_executor::value::Value;
 use crate::function_executor::function_schema::FunctionSchema;
 use crate::function_executor::function_error::FunctionError;
+use crate::function_executor::function_context::FunctionContext;
+use std::rc::Rc;
 
 
 /// Executes function.
@@ -11,4 +13,37 @@ pub trait FunctionExecutor {
     /// * `input` value that retrieved from field or other function executor.
     /// * `arguments` list of arguments that was declared within template.
     fn call(&self, input: Value, arguments: &[Value]) -> Result<Value, FunctionError>;
-}
\ No newline at end of file
+    /// Executes function with access to context of function call. Called by template.
+    ///
+    /// Default implementation calls `FunctionExecutor::call`.
+    fn call_with_context(&self, input: Value, arguments: &[Value], _context: &FunctionContext) -> Result<Value, FunctionError> {
+        return self.call(input, arguments);
+    }
+    /// Validates arguments declared within template. Called once when template is built.
+    ///
+    /// Default implementation accepts any arguments that match the schema.
+    fn validate_arguments(&self, _arguments: &[Value]) -> Result<(), FunctionError> {
+        return Ok(());
+    }
+}
+
+impl <F>FunctionExecutor for Rc<F>
+    where
+        F: FunctionExecutor + ?Sized,
+{
+    fn schema(&self) -> FunctionSchema {
+        return self.as_ref().schema();
+    }
+
+    fn call(&self, input: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        return self.as_ref().call(input, arguments);
+    }
+
+    fn call_with_context(&self, input: Value, arguments: &[Value], context: &FunctionContext) -> Result<Value, FunctionError> {
+        return self.as_ref().call_with_context(input, arguments, context);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        return self.as_ref().validate_arguments(arguments);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::function_executor::function_executor::FunctionExecutor;
+use std::rc::Rc;
+
+/// Group of function executors registered together: `TemplateBuilder::with_module(functions::string::module())`.
+pub struct FunctionModule {
+    name: String,
+    functions: Vec<Rc<dyn FunctionExecutor>>,
+}
+
+impl FunctionModule {
+    /// Creates empty function module.
+    pub fn new(name: impl Into<String>) -> FunctionModule {
+        return FunctionModule {
+            name: name.into(),
+            functions: Vec::new(),
+        }
+    }
+
+    /// Adds function executor to module.
+    pub fn with_function(mut self, function_executor: impl FunctionExecutor + 'static) -> Self {
+        self.functions.push(Rc::new(function_executor));
+        return self;
+    }
+
+    /// Returns module name.
+    pub fn get_name(&self) -> &str {
+        return &self.name;
+    }
+
+    /// Returns function executors of module.
+    pub fn iter(&self) -> impl Iterator<Item=&dyn FunctionExecutor> {
+        return self
+            .functions
+            .iter()
+            .map(Rc::as_ref);
+    }
+
+    pub (crate) fn into_functions(self) -> Vec<Rc<dyn FunctionExecutor>> {
+        return self.functions;
+    }
+}
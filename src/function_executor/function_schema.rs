@@ -1,10 +1,21 @@
 use crate::function_executor::function_argument::FunctionArgument;
+use crate::function_executor::output_shape::OutputShape;
+use crate::function_executor::value_type::ValueType;
+use crate::function_executor::function_example::FunctionExample;
+use serde::ser::{Serialize, Serializer, SerializeStruct};
 
-/// Contains information about function: function name, argument types.
+/// Contains information about function: function name, description, input, argument and output types,
+/// output shape and examples.
 #[derive(Debug)]
 pub struct FunctionSchema {
     function_name: String,
+    description: Option<String>,
+    input_type: Option<ValueType>,
     arguments: Vec<FunctionArgument>,
+    output_type: Option<ValueType>,
+    output_shape: Option<OutputShape>,
+    examples: Vec<FunctionExample>,
+    uses_state: bool,
 }
 
 impl FunctionSchema {
@@ -22,24 +33,120 @@ impl FunctionSchema {
         }
         return FunctionSchema {
             function_name,
+            description: None,
+            input_type: None,
             arguments: Vec::new(),
+            output_type: None,
+            output_shape: None,
+            examples: Vec::new(),
+            uses_state: false,
         }
     }
 
+    /// Sets function description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        return self;
+    }
+
+    /// Adds example of function call. See `FunctionExample`.
+    pub fn with_example(mut self, example: FunctionExample) -> Self {
+        self.examples.push(example);
+        return self;
+    }
+
+    /// Sets type of input value.
+    ///
+    /// Functions with the same name and different input types are overloads of each other.
+    /// Function without input type accepts any value.
+    pub fn with_input_type(mut self, input_type: ValueType) -> Self {
+        self.input_type = Some(input_type);
+        return self;
+    }
+
+    /// Sets type of returned value. Used for resolving overloads of the next function when template is built.
+    pub fn with_output_type(mut self, output_type: ValueType) -> Self {
+        self.output_type = Some(output_type);
+        return self;
+    }
+
     /// Adds function argument.
     pub fn with_argument(mut self, argument: FunctionArgument) -> Self {
         self.arguments.push(argument);
         return self;
     }
 
+    /// Sets shape of string produced by function.
+    pub fn with_output_shape(mut self, output_shape: OutputShape) -> Self {
+        self.output_shape = Some(output_shape);
+        return self;
+    }
+
+    /// Marks function as stateful: result depends on render or batch state, see `FunctionContext::get_batch_state`.
+    ///
+    /// Placeholders calling stateful functions are not evaluated by `Template::bind`.
+    pub fn with_state(mut self) -> Self {
+        self.uses_state = true;
+        return self;
+    }
+
     /// Returns function name.
     pub fn get_function_name(&self) -> &String {
         return &self.function_name;
     }
 
+    /// Returns function description.
+    pub fn get_description(&self) -> Option<&str> {
+        return self.description.as_deref();
+    }
+
+    /// Returns examples of function call.
+    pub fn get_examples(&self) -> &[FunctionExample] {
+        return &self.examples;
+    }
+
+    /// Returns type of input value.
+    pub fn get_input_type(&self) -> Option<&ValueType> {
+        return self.input_type.as_ref();
+    }
+
+    /// Returns type of returned value.
+    pub fn get_output_type(&self) -> Option<&ValueType> {
+        return self.output_type.as_ref();
+    }
 
     /// Returns list of arguments.
     pub fn get_arguments(&self) -> &[FunctionArgument] {
         return &self.arguments;
     }
-}
\ No newline at end of file
+
+    /// Returns shape of string produced by function.
+    pub fn get_output_shape(&self) -> Option<&OutputShape> {
+        return self.output_shape.as_ref();
+    }
+
+    /// Checks whether function is stateful. See `FunctionSchema::with_state`.
+    pub fn uses_state(&self) -> bool {
+        return self.uses_state;
+    }
+
+    pub (crate) fn renamed(mut self, function_name: String) -> Self {
+        self.function_name = function_name;
+        return self;
+    }
+}
+impl Serialize for FunctionSchema {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer
+    {
+        let mut state = serializer.serialize_struct("FunctionSchema", 6)?;
+        state.serialize_field("name", &self.function_name)?;
+        state.serialize_field("description", &self.description)?;
+        state.serialize_field("input_type", &self.input_type.as_ref().map(ToString::to_string))?;
+        state.serialize_field("output_type", &self.output_type.as_ref().map(ToString::to_string))?;
+        state.serialize_field("arguments", &self.arguments)?;
+        state.serialize_field("examples", &self.examples)?;
+        return state.end();
+    }
+}
@@ -0,0 +1,46 @@
+use crate::function_executor::value::Value;
+use std::collections::HashMap;
+
+/// Mutable state of stateful functions: `seq:counter`, `string:dedupe`.
+///
+/// Render state lives while one value is templated, batch state is shared by values of `Template::render_batch`.
+/// See `FunctionContext::get_render_state` and `FunctionContext::get_batch_state`.
+#[derive(Debug)]
+pub struct FunctionState {
+    values: HashMap<String, Value>,
+}
+
+impl FunctionState {
+    /// Creates empty state.
+    pub fn new() -> FunctionState {
+        return FunctionState {
+            values: HashMap::new(),
+        }
+    }
+
+    /// Returns value stored by key. Keys are prefixed with function name by convention: `seq:counter:...`.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        return self.values.get(key);
+    }
+
+    /// Stores value by key and returns previous value.
+    pub fn set(&mut self, key: impl Into<String>, value: Value) -> Option<Value> {
+        return self.values.insert(key.into(), value);
+    }
+
+    /// Removes value stored by key.
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        return self.values.remove(key);
+    }
+
+    /// Checks whether value is stored by key.
+    pub fn contains(&self, key: &str) -> bool {
+        return self.values.contains_key(key);
+    }
+}
+
+impl Default for FunctionState {
+    fn default() -> Self {
+        return FunctionState::new();
+    }
+}
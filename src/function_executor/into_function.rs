@@ -0,0 +1,50 @@
+use crate::function_executor::fn_executor::FnExecutor;
+use crate::function_executor::from_value::FromValue;
+use crate::function_executor::from_argument::FromArgument;
+use crate::function_executor::into_function_result::IntoFunctionResult;
+use crate::function_executor::function_argument::FunctionArgument;
+use crate::function_executor::function_error::FunctionError;
+use crate::function_executor::value::Value;
+
+/// Rust closure or function pointer that can be registered as function executor.
+///
+/// Implemented for `Fn(I, A1, .., An) -> R` with up to 4 arguments where `I: FromValue`,
+/// `A1..An: FromArgument` and `R: IntoFunctionResult`.
+pub trait IntoFunction<Args> {
+    /// Creates function executor with name `function_name`.
+    fn into_function(self, function_name: String) -> FnExecutor;
+}
+
+macro_rules! impl_into_function {
+    ($($argument: ident),*) => {
+        impl <F, I, $($argument,)* R>IntoFunction<(I, $($argument,)*)> for F
+            where
+                F: Fn(I, $($argument,)*) -> R + 'static,
+                I: FromValue,
+                $($argument: FromArgument,)*
+                R: IntoFunctionResult,
+        {
+            fn into_function(self, function_name: String) -> FnExecutor {
+                let arguments: Vec<fn() -> FunctionArgument> = vec![$($argument::argument,)*];
+                let function = move |input: Value, arguments: &[Value]| -> Result<Value, FunctionError> {
+                    #[allow(unused_mut, unused_variables)]
+                    let mut arguments = arguments.iter().cloned();
+                    let result = self(
+                        I::from_value(input)?,
+                        $($argument::from_value(arguments.next().ok_or_else(|| {
+                            return FunctionError::msg("Function called with wrong number of arguments");
+                        })?)?,)*
+                    );
+                    return result.into_function_result();
+                };
+                return FnExecutor::from_parts(function_name, I::value_type(), R::output_type(), arguments, Box::new(function));
+            }
+        }
+    };
+}
+
+impl_into_function!();
+impl_into_function!(A1);
+impl_into_function!(A1, A2);
+impl_into_function!(A1, A2, A3);
+impl_into_function!(A1, A2, A3, A4);
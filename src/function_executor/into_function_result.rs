@@ -0,0 +1,41 @@
+use crate::function_executor::into_value::IntoValue;
+use crate::function_executor::value::Value;
+use crate::function_executor::value_type::ValueType;
+use crate::function_executor::function_error::FunctionError;
+
+/// Result of function registered by `TemplateBuilder::with_fn`: value or `Result` with value.
+pub trait IntoFunctionResult {
+    /// Returns type of produced value.
+    fn output_type() -> ValueType;
+    /// Converts result into value.
+    fn into_function_result(self) -> Result<Value, FunctionError>;
+}
+
+impl <T>IntoFunctionResult for T
+    where
+        T: IntoValue,
+{
+    fn output_type() -> ValueType {
+        return T::value_type();
+    }
+
+    fn into_function_result(self) -> Result<Value, FunctionError> {
+        return Ok(self.into_value());
+    }
+}
+
+impl <T, E>IntoFunctionResult for Result<T, E>
+    where
+        T: IntoValue,
+        E: Into<FunctionError>,
+{
+    fn output_type() -> ValueType {
+        return T::value_type();
+    }
+
+    fn into_function_result(self) -> Result<Value, FunctionError> {
+        return self
+            .map(T::into_value)
+            .map_err(Into::into);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::function_executor::value::Value;
+use crate::function_executor::value_type::ValueType;
+
+/// Converts rust type into `Value`. Used for results of functions registered by `TemplateBuilder::with_fn`.
+pub trait IntoValue {
+    /// Returns type of produced value.
+    fn value_type() -> ValueType;
+    /// Converts rust type into value.
+    fn into_value(self) -> Value;
+}
+
+impl IntoValue for Value {
+    fn value_type() -> ValueType {
+        return ValueType::Any;
+    }
+
+    fn into_value(self) -> Value {
+        return self;
+    }
+}
+
+impl IntoValue for String {
+    fn value_type() -> ValueType {
+        return ValueType::String;
+    }
+
+    fn into_value(self) -> Value {
+        return Value::String(self);
+    }
+}
+
+impl IntoValue for &str {
+    fn value_type() -> ValueType {
+        return ValueType::String;
+    }
+
+    fn into_value(self) -> Value {
+        return Value::String(self.to_string());
+    }
+}
+
+impl IntoValue for bool {
+    fn value_type() -> ValueType {
+        return ValueType::Bool;
+    }
+
+    fn into_value(self) -> Value {
+        return Value::Bool(self);
+    }
+}
+
+impl IntoValue for char {
+    fn value_type() -> ValueType {
+        return ValueType::Char;
+    }
+
+    fn into_value(self) -> Value {
+        return Value::Char(self);
+    }
+}
+
+impl IntoValue for f64 {
+    fn value_type() -> ValueType {
+        return ValueType::Float;
+    }
+
+    fn into_value(self) -> Value {
+        return Value::Float(self);
+    }
+}
+
+impl IntoValue for f32 {
+    fn value_type() -> ValueType {
+        return ValueType::Float;
+    }
+
+    fn into_value(self) -> Value {
+        return Value::Float(self as f64);
+    }
+}
+
+impl <T>IntoValue for Option<T>
+    where
+        T: IntoValue,
+{
+    fn value_type() -> ValueType {
+        return ValueType::option(T::value_type());
+    }
+
+    fn into_value(self) -> Value {
+        let value = self.map(|value| {
+            return Box::new(value.into_value());
+        });
+        return Value::Option(value);
+    }
+}
+
+impl <T>IntoValue for Vec<T>
+    where
+        T: IntoValue,
+{
+    fn value_type() -> ValueType {
+        return ValueType::seq(T::value_type());
+    }
+
+    fn into_value(self) -> Value {
+        let value = self
+            .into_iter()
+            .map(T::into_value)
+            .collect();
+        return Value::Seq(value);
+    }
+}
+
+macro_rules! impl_into_value_for_integer {
+    ($value_type: ident, $target: ty, $($integer: ty),*) => {
+        $(
+            impl IntoValue for $integer {
+                fn value_type() -> ValueType {
+                    return ValueType::$value_type;
+                }
+
+                fn into_value(self) -> Value {
+                    return Value::$value_type(self as $target);
+                }
+            }
+        )*
+    };
+}
+
+impl_into_value_for_integer!(UInt, u128, u8, u16, u32, u64, u128, usize);
+impl_into_value_for_integer!(Int, i128, i8, i16, i32, i64, i128, isize);
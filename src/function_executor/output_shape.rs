@@ -0,0 +1,66 @@
+use crate::function_executor::value::Value;
+
+/// Describes string produced by function: set of characters and minimal length.
+///
+/// Used for generating glob and regex patterns from template.
+#[derive(Debug, Clone)]
+pub struct OutputShape {
+    character_class: String,
+    min_length: usize,
+    min_length_argument: Option<usize>,
+}
+
+impl OutputShape {
+    /// Creates output shape with regex character class, for example `[0-9a-f]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `character_class` is empty.
+    pub fn new(character_class: impl Into<String>) -> OutputShape {
+        let character_class = character_class.into();
+        if character_class.is_empty() {
+            panic!("Passed character class is empty");
+        }
+        return OutputShape {
+            character_class,
+            min_length: 0,
+            min_length_argument: None,
+        }
+    }
+
+    /// Sets minimal length of string.
+    pub fn with_min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        return self;
+    }
+
+    /// Sets index of uint argument that contains minimal length of string.
+    ///
+    /// Resulting minimal length is the maximum of argument value and value passed into `with_min_length`.
+    pub fn with_min_length_argument(mut self, argument_index: usize) -> Self {
+        self.min_length_argument = Some(argument_index);
+        return self;
+    }
+
+    /// Returns regex character class.
+    pub fn get_character_class(&self) -> &String {
+        return &self.character_class;
+    }
+
+    /// Returns minimal length of string for passed function arguments.
+    pub fn get_min_length(&self, arguments: &[Value]) -> usize {
+        let argument_length = self
+            .min_length_argument
+            .and_then(|argument_index| {
+                return arguments.get(argument_index);
+            })
+            .and_then(|argument| {
+                return argument.as_uint().ok();
+            })
+            .map(|argument| {
+                return *argument as usize;
+            })
+            .unwrap_or(0);
+        return self.min_length.max(argument_length);
+    }
+}
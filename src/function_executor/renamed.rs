@@ -0,0 +1,44 @@
+use crate::function_executor::function_executor::FunctionExecutor;
+use crate::function_executor::function_schema::FunctionSchema;
+use crate::function_executor::function_error::FunctionError;
+use crate::function_executor::value::Value;
+use crate::function_executor::function_context::FunctionContext;
+
+/// Registers function executor under another name.
+///
+/// Used for adding overloads of generic functions: `Renamed::new("to_string", functions::uint::ToString)`.
+pub struct Renamed<F> {
+    function_name: String,
+    function_executor: F,
+}
+
+impl <F>Renamed<F> {
+    /// Creates function executor that is called by `function_name`.
+    pub fn new(function_name: impl Into<String>, function_executor: F) -> Renamed<F> {
+        return Renamed {
+            function_name: function_name.into(),
+            function_executor,
+        }
+    }
+}
+
+impl <F>FunctionExecutor for Renamed<F>
+    where
+        F: FunctionExecutor,
+{
+    fn schema(&self) -> FunctionSchema {
+        return self.function_executor.schema().renamed(self.function_name.clone());
+    }
+
+    fn call(&self, input: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        return self.function_executor.call(input, arguments);
+    }
+
+    fn call_with_context(&self, input: Value, arguments: &[Value], context: &FunctionContext) -> Result<Value, FunctionError> {
+        return self.function_executor.call_with_context(input, arguments, context);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        return self.function_executor.validate_arguments(arguments);
+    }
+}
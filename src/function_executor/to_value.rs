@@ -0,0 +1,125 @@
+use crate::function_executor::value::Value;
+
+/// Converts borrowed rust type into `Value`. Used for fields of structures with `#[derive(OnelineTemplate)]`.
+pub trait ToValue {
+    /// Converts rust type into value.
+    fn to_value(&self) -> Value;
+
+    /// Converts slice into value. Slice of `u8` is converted into bytes, other slices into sequence.
+    fn slice_to_value(slice: &[Self]) -> Value
+        where
+            Self: Sized,
+    {
+        let value = slice
+            .iter()
+            .map(Self::to_value)
+            .collect();
+        return Value::Seq(value);
+    }
+}
+
+impl <T>ToValue for &T
+    where
+        T: ToValue + ?Sized,
+{
+    fn to_value(&self) -> Value {
+        return (**self).to_value();
+    }
+}
+
+impl ToValue for Value {
+    fn to_value(&self) -> Value {
+        return self.clone();
+    }
+}
+
+impl ToValue for String {
+    fn to_value(&self) -> Value {
+        return Value::String(self.clone());
+    }
+}
+
+impl ToValue for str {
+    fn to_value(&self) -> Value {
+        return Value::String(self.to_string());
+    }
+}
+
+impl ToValue for bool {
+    fn to_value(&self) -> Value {
+        return Value::Bool(*self);
+    }
+}
+
+impl ToValue for char {
+    fn to_value(&self) -> Value {
+        return Value::Char(*self);
+    }
+}
+
+impl ToValue for f64 {
+    fn to_value(&self) -> Value {
+        return Value::Float(*self);
+    }
+}
+
+impl ToValue for f32 {
+    fn to_value(&self) -> Value {
+        return Value::Float(*self as f64);
+    }
+}
+
+impl <T>ToValue for Option<T>
+    where
+        T: ToValue,
+{
+    fn to_value(&self) -> Value {
+        let value = self.as_ref().map(|value| {
+            return Box::new(value.to_value());
+        });
+        return Value::Option(value);
+    }
+}
+
+impl <T>ToValue for Vec<T>
+    where
+        T: ToValue,
+{
+    fn to_value(&self) -> Value {
+        return self.as_slice().to_value();
+    }
+}
+
+impl <T>ToValue for [T]
+    where
+        T: ToValue,
+{
+    fn to_value(&self) -> Value {
+        return T::slice_to_value(self);
+    }
+}
+
+macro_rules! impl_to_value_for_integer {
+    ($value_type: ident, $target: ty, $($integer: ty),*) => {
+        $(
+            impl ToValue for $integer {
+                fn to_value(&self) -> Value {
+                    return Value::$value_type(*self as $target);
+                }
+            }
+        )*
+    };
+}
+
+impl ToValue for u8 {
+    fn to_value(&self) -> Value {
+        return Value::UInt(*self as u128);
+    }
+
+    fn slice_to_value(slice: &[Self]) -> Value {
+        return Value::Bytes(slice.to_vec());
+    }
+}
+
+impl_to_value_for_integer!(UInt, u128, u16, u32, u64, u128, usize);
+impl_to_value_for_integer!(Int, i128, i8, i16, i32, i64, i128, isize);
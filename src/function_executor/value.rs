@@ -13,7 +13,8 @@ use crate::function_executor::function_error::FunctionError;
 /// * `bool`   -> error. For convertation boolean type use `bool:to_string`
 /// * `option` -> error. For convertation option use `string:unwrap_or`, `uint:unwrap_or` or others.
 /// * `float`  -> error. For convertation option use `float:to_string`.
-#[derive(Debug)]
+/// * `seq`    -> error. For convertation sequence use `seq:join`.
+#[derive(Debug, Clone)]
 pub enum Value {
     /// Value that contains string type.
     String(String),
@@ -31,6 +32,8 @@ pub enum Value {
     Int(i128),
     /// Value that contains float value.
     Float(f64),
+    /// Value that contains sequence of values.
+    Seq(Vec<Value>),
 }
 
 impl Value {
@@ -153,6 +156,30 @@ impl Value {
         return Err(FunctionError::msg(format!("Trying to cast {:?} as option", self)));
     }
 
+    /// Trying to cast value as sequence.
+    pub fn as_seq(&self) -> Result<&Vec<Value>, FunctionError> {
+        if let Value::Seq(ref value) = self {
+            return Ok(value);
+        }
+        return Err(FunctionError::msg(format!("Trying to cast {:?} as seq", self)));
+    }
+
+    /// Trying to cast value as mutable sequence.
+    pub fn as_mut_seq(&mut self) -> Result<&mut Vec<Value>, FunctionError> {
+        if let Value::Seq(ref mut value) = self {
+            return Ok(value);
+        }
+        return Err(FunctionError::msg(format!("Trying to cast {:?} as seq", self)));
+    }
+
+    /// Trying to cast value as sequence.
+    pub fn into_seq(self) -> Result<Vec<Value>, FunctionError> {
+        if let Value::Seq(value) = self {
+            return Ok(value);
+        }
+        return Err(FunctionError::msg(format!("Trying to cast {:?} as seq", self)));
+    }
+
     /// Trying to cast value as uint.
     pub fn as_uint(&self) -> Result<&u128, FunctionError> {
         if let Value::UInt(ref value) = self {
@@ -256,6 +283,9 @@ impl Serialize for Value {
             Value::Float(ref value) => {
                 return value.serialize(serializer);
             },
+            Value::Seq(ref value) => {
+                return value.serialize(serializer);
+            },
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,164 @@
+use crate::function_executor::value::Value;
+use std::fmt;
+
+/// Type of `Value` accepted or produced by function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueType {
+    /// `string`
+    String,
+    /// `bool`
+    Bool,
+    /// `bytes`
+    Bytes,
+    /// `char`
+    Char,
+    /// `uint`
+    UInt,
+    /// `int`
+    Int,
+    /// `float`
+    Float,
+    /// `Option<T>`
+    Option(Box<ValueType>),
+    /// `Seq<T>`
+    Seq(Box<ValueType>),
+    /// Any type.
+    Any,
+}
+
+impl ValueType {
+    /// Creates `Option<T>` type.
+    pub fn option(value_type: ValueType) -> ValueType {
+        return ValueType::Option(Box::new(value_type));
+    }
+
+    /// Creates `Seq<T>` type.
+    pub fn seq(value_type: ValueType) -> ValueType {
+        return ValueType::Seq(Box::new(value_type));
+    }
+
+    /// Returns type of passed value. Type of empty option and empty sequence items is `any`.
+    pub fn of(value: &Value) -> ValueType {
+        match value {
+            Value::String(..) => {
+                return ValueType::String;
+            },
+            Value::Bool(..) => {
+                return ValueType::Bool;
+            },
+            Value::Bytes(..) => {
+                return ValueType::Bytes;
+            },
+            Value::Char(..) => {
+                return ValueType::Char;
+            },
+            Value::UInt(..) => {
+                return ValueType::UInt;
+            },
+            Value::Int(..) => {
+                return ValueType::Int;
+            },
+            Value::Float(..) => {
+                return ValueType::Float;
+            },
+            Value::Option(ref value) => {
+                let value_type = match value {
+                    Some(value) => ValueType::of(value),
+                    None => ValueType::Any,
+                };
+                return ValueType::option(value_type);
+            },
+            Value::Seq(ref items) => {
+                let value_type = match items.first() {
+                    Some(item) => ValueType::of(item),
+                    None => ValueType::Any,
+                };
+                return ValueType::seq(value_type);
+            },
+        }
+    }
+
+    /// Checks that value belongs to this type.
+    pub fn matches(&self, value: &Value) -> bool {
+        match (self, value) {
+            (ValueType::Any, _) => {
+                return true;
+            },
+            (ValueType::Option(ref value_type), Value::Option(ref value)) => {
+                match value {
+                    Some(value) => {
+                        return value_type.matches(value);
+                    },
+                    None => {
+                        return true;
+                    },
+                }
+            },
+            (ValueType::Seq(ref value_type), Value::Seq(ref items)) => {
+                return items
+                    .iter()
+                    .all(|item| {
+                        return value_type.matches(item);
+                    });
+            },
+            (value_type, value) => {
+                return value_type == &ValueType::of(value);
+            },
+        }
+    }
+
+    /// Checks that values of `other` type may belong to this type. `any` is compatible with every type.
+    pub fn is_compatible(&self, other: &ValueType) -> bool {
+        match (self, other) {
+            (ValueType::Any, _) | (_, ValueType::Any) => {
+                return true;
+            },
+            (ValueType::Option(ref left), ValueType::Option(ref right)) => {
+                return left.is_compatible(right);
+            },
+            (ValueType::Seq(ref left), ValueType::Seq(ref right)) => {
+                return left.is_compatible(right);
+            },
+            (left, right) => {
+                return left == right;
+            },
+        }
+    }
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueType::String => {
+                write!(f, "string")
+            },
+            ValueType::Bool => {
+                write!(f, "bool")
+            },
+            ValueType::Bytes => {
+                write!(f, "bytes")
+            },
+            ValueType::Char => {
+                write!(f, "char")
+            },
+            ValueType::UInt => {
+                write!(f, "uint")
+            },
+            ValueType::Int => {
+                write!(f, "int")
+            },
+            ValueType::Float => {
+                write!(f, "float")
+            },
+            ValueType::Option(ref value_type) => {
+                write!(f, "Option<{}>", value_type)
+            },
+            ValueType::Seq(ref value_type) => {
+                write!(f, "Seq<{}>", value_type)
+            },
+            ValueType::Any => {
+                write!(f, "any")
+            },
+        }
+    }
+}
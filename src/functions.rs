@@ -8,5 +8,27 @@ pub mod int;
 pub mod uint;
 /// float functions
 pub mod float;
+/// bytes functions
+pub mod bytes;
+/// char functions
+pub mod char;
+/// sequence functions
+pub mod seq;
+/// human-readable sizes and durations
+pub mod human;
+/// option functions
+pub mod option;
 /// functions for debugging
-pub mod debug;
\ No newline at end of file
+pub mod debug;
+/// generic functions dispatching on type of input value
+pub mod generic;
+mod format_width;
+/// date and time functions
+#[cfg(feature = "time")]
+pub mod time;
+/// hashing functions
+#[cfg(feature = "hash")]
+pub mod hash;
+/// regex functions
+#[cfg(feature = "regex")]
+pub mod regex;
\ No newline at end of file
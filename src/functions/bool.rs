@@ -1,4 +1,12 @@
+use crate::function_executor::FunctionModule;
 mod unwrap_or;
 pub use self::unwrap_or::UnwrapOr;
 mod to_string;
-pub use self::to_string::ToString;
\ No newline at end of file
+pub use self::to_string::ToString;
+
+/// Returns module with bool functions.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("bool")
+        .with_function(ToString)
+        .with_function(UnwrapOr);
+}
@@ -1,15 +1,16 @@
 use crate::function_executor::*;
 
 /// Function: `bool:to_string`
-/// 
-/// Input: `bool`
-///
-/// Returns `string`
 pub struct ToString;
 
 impl FunctionExecutor for ToString {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("bool:to_string")
+            .with_description("Converts bool into string. Returns `string`.")
+            .with_input_type(ValueType::Bool)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[a-z]").with_min_length(4))
+            .with_example(FunctionExample::new(true, "bool:to_string()", "true"))
     }
 
     fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
@@ -1,18 +1,16 @@
 use crate::function_executor::*;
 
 /// Function: `bool:unwrap_or`
-/// 
-/// Input: `Option<bool>`
-///
-/// * first argument: default bool
-///
-/// Returns `bool`
 pub struct UnwrapOr;
 
 impl FunctionExecutor for UnwrapOr {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("bool:unwrap_or")
-            .with_argument(FunctionArgument::bool())
+            .with_description("Unwraps optional bool, empty option gives default. Returns `bool`.")
+            .with_input_type(ValueType::option(ValueType::Bool))
+            .with_output_type(ValueType::Bool)
+            .with_argument(FunctionArgument::bool().with_name("default").with_description("default bool"))
+            .with_example(FunctionExample::new(None::<bool>, "bool:unwrap_or(true)|bool:to_string()", "true"))
     }
 
     fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
@@ -22,7 +20,7 @@ impl FunctionExecutor for UnwrapOr {
                 value.into_bool()?
             },
             None => {
-                arguments[0].as_bool()?.clone()
+                *arguments[0].as_bool()?
             },
         };
         let value = Value::Bool(value);
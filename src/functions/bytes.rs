@@ -0,0 +1,34 @@
+use crate::function_executor::FunctionModule;
+mod hex;
+pub use self::hex::Hex;
+
+mod hex_upper;
+pub use self::hex_upper::HexUpper;
+mod base64;
+pub use self::base64::Base64;
+mod base64url;
+pub use self::base64url::Base64Url;
+mod base32;
+pub use self::base32::Base32;
+mod base58;
+pub use self::base58::Base58;
+mod len;
+pub use self::len::Len;
+mod slice;
+pub use self::slice::Slice;
+mod to_string;
+pub use self::to_string::ToString;
+
+/// Returns module with bytes functions.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("bytes")
+        .with_function(Hex)
+        .with_function(HexUpper)
+        .with_function(Base64)
+        .with_function(Base64Url)
+        .with_function(Base32)
+        .with_function(Base58)
+        .with_function(Len)
+        .with_function(Slice)
+        .with_function(ToString);
+}
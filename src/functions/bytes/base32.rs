@@ -0,0 +1,23 @@
+use crate::function_executor::*;
+use data_encoding::BASE32;
+
+/// Function: `bytes:base32`
+pub struct Base32;
+
+impl FunctionExecutor for Base32 {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("bytes:base32")
+            .with_description("Encodes bytes using base32. Returns standard padded base32 `string`.")
+            .with_input_type(ValueType::Bytes)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[A-Z2-7=]"))
+            .with_example(FunctionExample::new(Value::Bytes(b"hi".to_vec()), "bytes:base32()", "NBUQ===="))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_bytes()?;
+        let value = BASE32.encode(value);
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
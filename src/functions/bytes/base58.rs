@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+
+/// Function: `bytes:base58`
+pub struct Base58;
+
+impl FunctionExecutor for Base58 {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("bytes:base58")
+            .with_description("Encodes bytes using base58. Returns base58 `string` using Bitcoin alphabet.")
+            .with_input_type(ValueType::Bytes)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[1-9A-HJ-NP-Za-km-z]"))
+            .with_example(FunctionExample::new(Value::Bytes(b"hi".to_vec()), "bytes:base58()", "8wr"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_bytes()?;
+        let value = bs58::encode(value).into_string();
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
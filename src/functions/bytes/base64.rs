@@ -0,0 +1,23 @@
+use crate::function_executor::*;
+use data_encoding::BASE64;
+
+/// Function: `bytes:base64`
+pub struct Base64;
+
+impl FunctionExecutor for Base64 {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("bytes:base64")
+            .with_description("Encodes bytes using base64. Returns standard padded base64 `string`.")
+            .with_input_type(ValueType::Bytes)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[A-Za-z0-9+/=]"))
+            .with_example(FunctionExample::new(Value::Bytes(b"hi".to_vec()), "bytes:base64()", "aGk="))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_bytes()?;
+        let value = BASE64.encode(value);
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
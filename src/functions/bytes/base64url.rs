@@ -0,0 +1,23 @@
+use crate::function_executor::*;
+use data_encoding::BASE64URL_NOPAD;
+
+/// Function: `bytes:base64url`
+pub struct Base64Url;
+
+impl FunctionExecutor for Base64Url {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("bytes:base64url")
+            .with_description("Encodes bytes using URL-safe base64. Returns URL-safe base64 without padding `string`.")
+            .with_input_type(ValueType::Bytes)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[A-Za-z0-9_-]"))
+            .with_example(FunctionExample::new(Value::Bytes(b"hi".to_vec()), "bytes:base64url()", "aGk"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_bytes()?;
+        let value = BASE64URL_NOPAD.encode(value);
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
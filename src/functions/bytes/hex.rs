@@ -0,0 +1,25 @@
+use crate::function_executor::*;
+
+/// Function: `bytes:hex`
+pub struct Hex;
+
+impl FunctionExecutor for Hex {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("bytes:hex")
+            .with_description("Encodes bytes as lowercase hex. Returns lowercase hex `string`.")
+            .with_input_type(ValueType::Bytes)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[0-9a-f]"))
+            .with_example(FunctionExample::new(Value::Bytes(vec![1, 171]), "bytes:hex()", "01ab"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_bytes()?;
+        let mut result = String::with_capacity(value.len() * 2);
+        for byte in value.iter() {
+            result += &format!("{:02x}", byte);
+        }
+        let value = Value::String(result);
+        return Ok(value);
+    }
+}
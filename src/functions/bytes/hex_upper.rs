@@ -0,0 +1,23 @@
+use crate::function_executor::*;
+use data_encoding::HEXUPPER;
+
+/// Function: `bytes:hex_upper`
+pub struct HexUpper;
+
+impl FunctionExecutor for HexUpper {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("bytes:hex_upper")
+            .with_description("Encodes bytes as uppercase hex. Returns uppercase hex `string`.")
+            .with_input_type(ValueType::Bytes)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[0-9A-F]"))
+            .with_example(FunctionExample::new(Value::Bytes(vec![1, 171]), "bytes:hex_upper()", "01AB"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_bytes()?;
+        let value = HEXUPPER.encode(value);
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
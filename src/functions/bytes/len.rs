@@ -0,0 +1,21 @@
+use crate::function_executor::*;
+
+/// Function: `bytes:len`
+pub struct Len;
+
+impl FunctionExecutor for Len {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("bytes:len")
+            .with_description("Counts bytes. Returns `uint` count of bytes.")
+            .with_input_type(ValueType::Bytes)
+            .with_output_type(ValueType::UInt)
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new(Value::Bytes(b"hi".to_vec()), "bytes:len()|uint:to_string()", "2"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_bytes()?;
+        let value = Value::UInt(value.len() as u128);
+        return Ok(value);
+    }
+}
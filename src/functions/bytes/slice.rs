@@ -0,0 +1,28 @@
+use crate::function_executor::*;
+use std::convert::TryFrom;
+
+/// Function: `bytes:slice`
+pub struct Slice;
+
+impl FunctionExecutor for Slice {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("bytes:slice")
+            .with_description("Takes range of bytes. Returns `bytes`. Range is truncated to the end of input.")
+            .with_input_type(ValueType::Bytes)
+            .with_output_type(ValueType::Bytes)
+            .with_argument(FunctionArgument::uint().with_name("offset"))
+            .with_argument(FunctionArgument::uint().with_name("length"))
+            .with_example(FunctionExample::new(Value::Bytes(vec![1, 2, 3, 4]), "bytes:slice(1uint, 2uint)|bytes:hex()", "0203"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_bytes()?;
+        let begin = arguments[0].as_uint()?;
+        let begin = usize::try_from(*begin).unwrap_or(usize::MAX).min(value.len());
+        let length = arguments[1].as_uint()?;
+        let length = usize::try_from(*length).unwrap_or(usize::MAX);
+        let end = begin.saturating_add(length).min(value.len());
+        let value = Value::Bytes(value[begin..end].to_vec());
+        return Ok(value);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+
+/// Function: `bytes:to_string`
+pub struct ToString;
+
+impl FunctionExecutor for ToString {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("bytes:to_string")
+            .with_description("Decodes bytes as UTF-8. Returns `string`, fails on invalid UTF-8.")
+            .with_input_type(ValueType::Bytes)
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new(Value::Bytes(b"hi".to_vec()), "bytes:to_string()", "hi"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_bytes()?;
+        let value = String::from_utf8(value.clone())
+            .map_err(|error| FunctionError::msg(format!("Bytes are not valid UTF-8: {}", error)))?;
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,9 @@
+use crate::function_executor::FunctionModule;
+mod to_string;
+pub use self::to_string::ToString;
+
+/// Returns module with char functions.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("char")
+        .with_function(ToString);
+}
@@ -0,0 +1,20 @@
+use crate::function_executor::*;
+
+/// Function: `char:to_string`
+pub struct ToString;
+
+impl FunctionExecutor for ToString {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("char:to_string")
+            .with_description("Converts char into string. Returns `string`.")
+            .with_input_type(ValueType::Char)
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new('x', "char:to_string()", "x"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_char()?;
+        let value = Value::String(value.to_string());
+        return Ok(value);
+    }
+}
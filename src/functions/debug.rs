@@ -1,2 +1,9 @@
+use crate::function_executor::FunctionModule;
 mod debug_type;
-pub use self::debug_type::DebugType;
\ No newline at end of file
+pub use self::debug_type::DebugType;
+
+/// Returns module with functions for debugging.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("debug")
+        .with_function(DebugType);
+}
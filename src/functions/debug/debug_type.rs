@@ -1,15 +1,14 @@
 use crate::function_executor::*;
 
 /// Function: `debug:type`
-/// 
-/// Input: any type
-///
-/// Returns `string`
 pub struct DebugType;
 
 impl FunctionExecutor for DebugType {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("debug:type")
+            .with_description("Describes type of input value. Input: any type. Returns `string`.")
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new(5u32, "debug:type()", "UInt"))
     }
 
     fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
@@ -53,5 +52,8 @@ fn inspect_type(value: Value) -> String {
         Value::Float(..) => {
             return "Float".to_string();
         },
+        Value::Seq(..) => {
+            return "Seq".to_string();
+        },
     }
 }
\ No newline at end of file
@@ -1,2 +1,49 @@
+use crate::function_executor::FunctionModule;
 mod to_string;
-pub use self::to_string::ToString;
\ No newline at end of file
+pub use self::to_string::ToString;
+mod float_to_int;
+mod fixed;
+pub use self::fixed::Fixed;
+mod sci;
+pub use self::sci::Sci;
+mod percent;
+pub use self::percent::Percent;
+mod round;
+pub use self::round::Round;
+mod floor;
+pub use self::floor::Floor;
+mod ceil;
+pub use self::ceil::Ceil;
+mod add;
+pub use self::add::Add;
+mod sub;
+pub use self::sub::Sub;
+mod mul;
+pub use self::mul::Mul;
+mod div;
+pub use self::div::Div;
+mod rem;
+pub use self::rem::Rem;
+mod to_int;
+pub use self::to_int::ToInt;
+mod unwrap_or;
+pub use self::unwrap_or::UnwrapOr;
+
+/// Returns module with float functions.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("float")
+        .with_function(ToString)
+        .with_function(Fixed)
+        .with_function(Sci)
+        .with_function(Percent)
+        .with_function(Round)
+        .with_function(Floor)
+        .with_function(Ceil)
+        .with_function(Add)
+        .with_function(Sub)
+        .with_function(Mul)
+        .with_function(Div)
+        .with_function(Rem)
+        .with_function(ToInt)
+        .with_function(UnwrapOr);
+}
@@ -0,0 +1,26 @@
+use crate::function_executor::*;
+
+/// Function: `float:add`
+pub struct Add;
+
+impl FunctionExecutor for Add {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("float:add")
+            .with_description("Adds number to float. Returns `float` sum. Infinite or NaN result is an error.")
+            .with_input_type(ValueType::Float)
+            .with_output_type(ValueType::Float)
+            .with_argument(FunctionArgument::float().with_name("operand"))
+            .with_example(FunctionExample::new(1.5f64, "float:add(1float)|float:to_string()", "2.5"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_float()?;
+        let argument = arguments[0].as_float()?;
+        let result = value + argument;
+        if !result.is_finite() {
+            return Err(FunctionError::msg(format!("Result of {} + {} is not finite", value, argument)));
+        }
+        let value = Value::Float(result);
+        return Ok(value);
+    }
+}
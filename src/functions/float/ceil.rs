@@ -0,0 +1,23 @@
+use crate::function_executor::*;
+use super::float_to_int::float_to_int;
+
+/// Function: `float:ceil`
+pub struct Ceil;
+
+impl FunctionExecutor for Ceil {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("float:ceil")
+            .with_description("Rounds float up. Returns `int` rounded toward positive infinity.")
+            .with_input_type(ValueType::Float)
+            .with_output_type(ValueType::Int)
+            .with_output_shape(OutputShape::new("[0-9-]").with_min_length(1))
+            .with_example(FunctionExample::new(1.2f64, "float:ceil()|int:to_string()", "2"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_float()?;
+        let value = float_to_int(value.ceil())?;
+        let value = Value::Int(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::function_executor::*;
+use crate::functions::format_width::read_format_width;
+
+/// Function: `float:fixed`
+pub struct Fixed;
+
+impl FunctionExecutor for Fixed {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("float:fixed")
+            .with_description("Formats float with fixed number of digits after decimal point. Returns `string`.")
+            .with_input_type(ValueType::Float)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::uint().with_name("precision").with_description("number of digits after decimal point"))
+            .with_example(FunctionExample::new(1.2345f64, "float:fixed(2uint)", "1.23"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_float()?;
+        let precision = read_format_width(&arguments[0], "precision")?;
+        let value = format!("{:.*}", precision, value);
+        let value = Value::String(value);
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let _ = read_format_width(&arguments[0], "precision")?;
+        return Ok(());
+    }
+}
@@ -0,0 +1,9 @@
+use crate::function_executor::*;
+
+/// Converts integral float into int.
+pub fn float_to_int(value: f64) -> Result<i128, FunctionError> {
+    if !value.is_finite() || value < i128::MIN as f64 || value >= i128::MAX as f64 {
+        return Err(FunctionError::msg(format!("Unable to convert float {} into int", value)));
+    }
+    return Ok(value as i128);
+}
@@ -0,0 +1,23 @@
+use crate::function_executor::*;
+use super::float_to_int::float_to_int;
+
+/// Function: `float:floor`
+pub struct Floor;
+
+impl FunctionExecutor for Floor {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("float:floor")
+            .with_description("Rounds float down. Returns `int` rounded toward negative infinity.")
+            .with_input_type(ValueType::Float)
+            .with_output_type(ValueType::Int)
+            .with_output_shape(OutputShape::new("[0-9-]").with_min_length(1))
+            .with_example(FunctionExample::new(1.8f64, "float:floor()|int:to_string()", "1"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_float()?;
+        let value = float_to_int(value.floor())?;
+        let value = Value::Int(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::function_executor::*;
+use crate::functions::format_width::read_format_width;
+
+/// Function: `float:percent`
+pub struct Percent;
+
+impl FunctionExecutor for Percent {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("float:percent")
+            .with_description("Formats float as percentage. Returns `string` with value multiplied by 100 and `%` sign: `0.125` gives `12.5%`.")
+            .with_input_type(ValueType::Float)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::uint().with_name("precision").with_description("number of digits after decimal point"))
+            .with_example(FunctionExample::new(0.125f64, "float:percent(1uint)", "12.5%"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_float()?;
+        let precision = read_format_width(&arguments[0], "precision")?;
+        let value = format!("{:.*}%", precision, value * 100.0);
+        let value = Value::String(value);
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let _ = read_format_width(&arguments[0], "precision")?;
+        return Ok(());
+    }
+}
@@ -0,0 +1,23 @@
+use crate::function_executor::*;
+use super::float_to_int::float_to_int;
+
+/// Function: `float:round`
+pub struct Round;
+
+impl FunctionExecutor for Round {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("float:round")
+            .with_description("Rounds float to the nearest integer. Returns `int` rounded half away from zero.")
+            .with_input_type(ValueType::Float)
+            .with_output_type(ValueType::Int)
+            .with_output_shape(OutputShape::new("[0-9-]").with_min_length(1))
+            .with_example(FunctionExample::new(2.5f64, "float:round()|int:to_string()", "3"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_float()?;
+        let value = float_to_int(value.round())?;
+        let value = Value::Int(value);
+        return Ok(value);
+    }
+}
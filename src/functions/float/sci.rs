@@ -0,0 +1,29 @@
+use crate::function_executor::*;
+use crate::functions::format_width::read_format_width;
+
+/// Function: `float:sci`
+pub struct Sci;
+
+impl FunctionExecutor for Sci {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("float:sci")
+            .with_description("Formats float in scientific notation. Returns `string` in scientific notation: `1.23e4`.")
+            .with_input_type(ValueType::Float)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::uint().with_name("precision").with_description("number of digits after decimal point"))
+            .with_example(FunctionExample::new(12345f64, "float:sci(2uint)", "1.23e4"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_float()?;
+        let precision = read_format_width(&arguments[0], "precision")?;
+        let value = format!("{:.*e}", precision, value);
+        let value = Value::String(value);
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let _ = read_format_width(&arguments[0], "precision")?;
+        return Ok(());
+    }
+}
@@ -0,0 +1,26 @@
+use crate::function_executor::*;
+
+/// Function: `float:sub`
+pub struct Sub;
+
+impl FunctionExecutor for Sub {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("float:sub")
+            .with_description("Subtracts number from float. Returns `float` difference. Infinite or NaN result is an error.")
+            .with_input_type(ValueType::Float)
+            .with_output_type(ValueType::Float)
+            .with_argument(FunctionArgument::float().with_name("operand"))
+            .with_example(FunctionExample::new(2.5f64, "float:sub(1float)|float:to_string()", "1.5"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_float()?;
+        let argument = arguments[0].as_float()?;
+        let result = value - argument;
+        if !result.is_finite() {
+            return Err(FunctionError::msg(format!("Result of {} - {} is not finite", value, argument)));
+        }
+        let value = Value::Float(result);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,23 @@
+use crate::function_executor::*;
+use super::float_to_int::float_to_int;
+
+/// Function: `float:to_int`
+pub struct ToInt;
+
+impl FunctionExecutor for ToInt {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("float:to_int")
+            .with_description("Converts float into int. Returns `int` with fractional part discarded. NaN, infinite and out of range values are errors.")
+            .with_input_type(ValueType::Float)
+            .with_output_type(ValueType::Int)
+            .with_output_shape(OutputShape::new("[0-9-]").with_min_length(1))
+            .with_example(FunctionExample::new(-2.7f64, "float:to_int()|int:to_string()", "-2"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_float()?;
+        let value = float_to_int(value.trunc())?;
+        let value = Value::Int(value);
+        return Ok(value);
+    }
+}
@@ -1,15 +1,15 @@
 use crate::function_executor::*;
 
 /// Function: `float:to_string`
-/// 
-/// Input: `float`
-///
-/// Returns `string`
 pub struct ToString;
 
 impl FunctionExecutor for ToString {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("float:to_string")
+            .with_description("Converts float into string. Returns `string`.")
+            .with_input_type(ValueType::Float)
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new(0.5f64, "float:to_string()", "0.5"))
     }
 
     fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
@@ -0,0 +1,29 @@
+use crate::function_executor::*;
+
+/// Function: `float:unwrap_or`
+pub struct UnwrapOr;
+
+impl FunctionExecutor for UnwrapOr {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("float:unwrap_or")
+            .with_description("Unwraps optional float, empty option gives default. Returns `float`.")
+            .with_input_type(ValueType::option(ValueType::Float))
+            .with_output_type(ValueType::Float)
+            .with_argument(FunctionArgument::float().with_name("default").with_description("default float"))
+            .with_example(FunctionExample::new(None::<f64>, "float:unwrap_or(0.5float)|float:to_string()", "0.5"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.into_option()?;
+        let value = match value {
+            Some(value) => {
+                value.floato_float()?
+            },
+            None => {
+                *arguments[0].as_float()?
+            },
+        };
+        let value = Value::Float(value);
+        return Ok(value);
+    }
+}
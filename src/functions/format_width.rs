@@ -0,0 +1,18 @@
+use crate::function_executor::{FunctionError, Value};
+use std::convert::TryFrom;
+
+/// The largest width or precision supported by `format!`.
+pub const MAX_FORMAT_WIDTH: usize = u16::MAX as usize;
+
+/// Reads width or precision argument. Values above `MAX_FORMAT_WIDTH` are rejected instead of panicking in `format!`.
+pub fn read_format_width(value: &Value, argument_name: &str) -> Result<usize, FunctionError> {
+    let width = value.as_uint()?;
+    return usize::try_from(*width)
+        .ok()
+        .filter(|width| {
+            return *width <= MAX_FORMAT_WIDTH;
+        })
+        .ok_or_else(|| {
+            return FunctionError::msg(format!("Argument `{}` must not exceed {}, got {}", argument_name, MAX_FORMAT_WIDTH, width));
+        });
+}
@@ -0,0 +1,43 @@
+use crate::function_executor::{FunctionModule, Renamed};
+use crate::functions;
+
+/// Returns module with generic functions dispatching on type of input value: `to_string`, `unwrap_or`, `hex`, `len` and others.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("generic")
+        .with_function(Renamed::new("to_string", functions::bool::ToString))
+        .with_function(Renamed::new("to_string", functions::int::ToString))
+        .with_function(Renamed::new("to_string", functions::uint::ToString))
+        .with_function(Renamed::new("to_string", functions::float::ToString))
+        .with_function(Renamed::new("to_string", functions::string::ToString))
+        .with_function(Renamed::new("to_string", functions::char::ToString))
+        .with_function(Renamed::new("to_string", functions::bytes::ToString))
+        .with_function(Renamed::new("unwrap_or", functions::string::UnwrapOr))
+        .with_function(Renamed::new("unwrap_or", functions::bool::UnwrapOr))
+        .with_function(Renamed::new("unwrap_or", functions::uint::UnwrapOr))
+        .with_function(Renamed::new("unwrap_or", functions::int::UnwrapOr))
+        .with_function(Renamed::new("unwrap_or", functions::float::UnwrapOr))
+        .with_function(Renamed::new("hex", functions::bytes::Hex))
+        .with_function(Renamed::new("hex", functions::int::Hex))
+        .with_function(Renamed::new("hex", functions::uint::Hex))
+        .with_function(Renamed::new("hex_fmt", functions::int::HexFmt))
+        .with_function(Renamed::new("hex_fmt", functions::uint::HexFmt))
+        .with_function(Renamed::new("pad", functions::int::Pad))
+        .with_function(Renamed::new("pad", functions::uint::Pad))
+        .with_function(Renamed::new("add", functions::int::Add))
+        .with_function(Renamed::new("add", functions::uint::Add))
+        .with_function(Renamed::new("add", functions::float::Add))
+        .with_function(Renamed::new("sub", functions::int::Sub))
+        .with_function(Renamed::new("sub", functions::uint::Sub))
+        .with_function(Renamed::new("sub", functions::float::Sub))
+        .with_function(Renamed::new("mul", functions::int::Mul))
+        .with_function(Renamed::new("mul", functions::uint::Mul))
+        .with_function(Renamed::new("mul", functions::float::Mul))
+        .with_function(Renamed::new("div", functions::int::Div))
+        .with_function(Renamed::new("div", functions::uint::Div))
+        .with_function(Renamed::new("div", functions::float::Div))
+        .with_function(Renamed::new("rem", functions::int::Rem))
+        .with_function(Renamed::new("rem", functions::uint::Rem))
+        .with_function(Renamed::new("rem", functions::float::Rem))
+        .with_function(Renamed::new("len", functions::bytes::Len))
+        .with_function(Renamed::new("len", functions::seq::Len));
+}
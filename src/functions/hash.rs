@@ -0,0 +1,22 @@
+use crate::function_executor::FunctionModule;
+mod hash_input;
+mod sha256;
+pub use self::sha256::Sha256;
+mod md5;
+pub use self::md5::Md5;
+mod crc32;
+pub use self::crc32::Crc32;
+mod xxh3;
+pub use self::xxh3::Xxh3;
+mod fnv1a;
+pub use self::fnv1a::Fnv1a;
+
+/// Returns module with hashing functions.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("hash")
+        .with_function(Sha256)
+        .with_function(Md5)
+        .with_function(Crc32)
+        .with_function(Xxh3)
+        .with_function(Fnv1a);
+}
@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+use super::hash_input::read_hash_input;
+
+/// Function: `hash:crc32`
+pub struct Crc32;
+
+impl FunctionExecutor for Crc32 {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("hash:crc32")
+            .with_description("Computes CRC-32 checksum. Input: `string` or `bytes`. Returns `uint` CRC-32 checksum.")
+            .with_output_type(ValueType::UInt)
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new("hello", "hash:crc32()|uint:hex()", "3610a686"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = read_hash_input(&value)?;
+        let value = crc32fast::hash(value);
+        let value = Value::UInt(value as u128);
+        return Ok(value);
+    }
+}
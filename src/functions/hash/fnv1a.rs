@@ -0,0 +1,29 @@
+use crate::function_executor::*;
+use super::hash_input::read_hash_input;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Function: `hash:fnv1a`
+pub struct Fnv1a;
+
+impl FunctionExecutor for Fnv1a {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("hash:fnv1a")
+            .with_description("Computes 64-bit FNV-1a hash. Input: `string` or `bytes`. Returns `uint` 64-bit FNV-1a hash.")
+            .with_output_type(ValueType::UInt)
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new("hello", "hash:fnv1a()|uint:hex()", "a430d84680aabd0b"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = read_hash_input(&value)?;
+        let mut hash = FNV_OFFSET_BASIS;
+        for byte in value.iter() {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+        let value = Value::UInt(hash as u128);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::function_executor::*;
+
+/// Reads bytes for hashing from `string` or `bytes`.
+pub fn read_hash_input(value: &Value) -> Result<&[u8], FunctionError> {
+    match value {
+        Value::String(value) => {
+            return Ok(value.as_bytes());
+        },
+        Value::Bytes(value) => {
+            return Ok(value.as_slice());
+        },
+        _ => {
+            return Err(FunctionError::msg(format!("Trying to cast {:?} as string or bytes", value)));
+        },
+    }
+}
@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+use super::hash_input::read_hash_input;
+use md5::{Digest, Md5 as Md5Hasher};
+
+/// Function: `hash:md5`
+pub struct Md5;
+
+impl FunctionExecutor for Md5 {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("hash:md5")
+            .with_description("Computes MD5 digest. Input: `string` or `bytes`. Returns `bytes` MD5 digest.")
+            .with_output_type(ValueType::Bytes)
+            .with_example(FunctionExample::new("hello", "hash:md5()|bytes:hex()", "5d41402abc4b2a76b9719d911017c592"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = read_hash_input(&value)?;
+        let value = Md5Hasher::digest(value).to_vec();
+        let value = Value::Bytes(value);
+        return Ok(value);
+    }
+}
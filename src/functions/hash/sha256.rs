@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+use super::hash_input::read_hash_input;
+use sha2::{Digest, Sha256 as Sha256Hasher};
+
+/// Function: `hash:sha256`
+pub struct Sha256;
+
+impl FunctionExecutor for Sha256 {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("hash:sha256")
+            .with_description("Computes SHA-256 digest. Input: `string` or `bytes`. Returns `bytes` SHA-256 digest.")
+            .with_output_type(ValueType::Bytes)
+            .with_example(FunctionExample::new("hello", "hash:sha256()|bytes:hex()", "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = read_hash_input(&value)?;
+        let value = Sha256Hasher::digest(value).to_vec();
+        let value = Value::Bytes(value);
+        return Ok(value);
+    }
+}
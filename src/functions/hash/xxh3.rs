@@ -0,0 +1,23 @@
+use crate::function_executor::*;
+use super::hash_input::read_hash_input;
+use xxhash_rust::xxh3::xxh3_64;
+
+/// Function: `hash:xxh3`
+pub struct Xxh3;
+
+impl FunctionExecutor for Xxh3 {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("hash:xxh3")
+            .with_description("Computes 64-bit XXH3 hash. Input: `string` or `bytes`. Returns `uint` 64-bit XXH3 hash.")
+            .with_output_type(ValueType::UInt)
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new("hello", "hash:xxh3()|uint:hex()", "9555e8555c62dcfd"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = read_hash_input(&value)?;
+        let value = xxh3_64(value);
+        let value = Value::UInt(value as u128);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::function_executor::FunctionModule;
+mod duration;
+mod bytes_human;
+pub use self::bytes_human::BytesHuman;
+mod duration_secs;
+pub use self::duration_secs::DurationSecs;
+mod parse_duration;
+pub use self::parse_duration::ParseDuration;
+
+/// Returns module with human-readable sizes and durations.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("human")
+        .with_function(BytesHuman)
+        .with_function(DurationSecs)
+        .with_function(ParseDuration);
+}
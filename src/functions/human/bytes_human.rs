@@ -0,0 +1,62 @@
+use crate::function_executor::*;
+
+/// Function: `uint:bytes_human`
+pub struct BytesHuman;
+
+impl FunctionExecutor for BytesHuman {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:bytes_human")
+            .with_description("Formats count of bytes as human-readable size. Input: `uint` count of bytes. Returns `string` with at most one digit after decimal point: `1.5MiB`.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::string().with_name("units").with_description("unit system: `iec` for powers of 1024 (`KiB`, `MiB`) or `si` for powers of 1000 (`kB`, `MB`)"))
+            .with_example(FunctionExample::new(1572864u32, "uint:bytes_human('iec')", "1.5MiB"))
+            .with_example(FunctionExample::new(1500u32, "uint:bytes_human('si')", "1.5kB"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let (base, units) = read_unit_system(&arguments[0])?;
+        let mut size = *value as f64;
+        let mut unit_index = 0;
+        while size >= base && unit_index + 1 < units.len() {
+            size /= base;
+            unit_index += 1;
+        }
+        let mut size = (size * 10.0).round() / 10.0;
+        if size >= base && unit_index + 1 < units.len() {
+            size = (size / base * 10.0).round() / 10.0;
+            unit_index += 1;
+        }
+        let value = if size.fract() == 0.0 {
+            format!("{:.0}{}", size, units[unit_index])
+        } else {
+            format!("{:.1}{}", size, units[unit_index])
+        };
+        let value = Value::String(value);
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let _ = read_unit_system(&arguments[0])?;
+        return Ok(());
+    }
+}
+
+const IEC_UNITS: &[&str] = &["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB", "ZiB", "YiB"];
+const SI_UNITS: &[&str] = &["B", "kB", "MB", "GB", "TB", "PB", "EB", "ZB", "YB"];
+
+fn read_unit_system(value: &Value) -> Result<(f64, &'static [&'static str]), FunctionError> {
+    let value = value.as_string()?;
+    match value.as_str() {
+        "iec" => {
+            return Ok((1024.0, IEC_UNITS));
+        },
+        "si" => {
+            return Ok((1000.0, SI_UNITS));
+        },
+        _ => {
+            return Err(FunctionError::msg(format!("Unknown unit system `{}`, expected `iec` or `si`", value)));
+        },
+    }
+}
@@ -0,0 +1,8 @@
+/// Units of duration with their length in seconds, from the largest to the smallest.
+pub const DURATION_UNITS: &[(char, u128)] = &[
+    ('w', 7 * 24 * 60 * 60),
+    ('d', 24 * 60 * 60),
+    ('h', 60 * 60),
+    ('m', 60),
+    ('s', 1),
+];
@@ -0,0 +1,34 @@
+use crate::function_executor::*;
+use super::duration::DURATION_UNITS;
+
+/// Function: `uint:duration_secs`
+pub struct DurationSecs;
+
+impl FunctionExecutor for DurationSecs {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:duration_secs")
+            .with_description("Formats count of seconds as human-readable duration. Input: `uint` count of seconds. Returns `string` with days, hours, minutes and seconds: `1d2h30m`. Zero components are skipped, zero duration gives `0s`.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[0-9dhms]").with_min_length(2))
+            .with_example(FunctionExample::new(95400u32, "uint:duration_secs()", "1d2h30m"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let mut rest = *value;
+        let mut result = String::new();
+        for &(unit, unit_secs) in DURATION_UNITS.iter().filter(|(unit, _)| *unit != 'w') {
+            let count = rest / unit_secs;
+            rest %= unit_secs;
+            if count > 0 {
+                result += &format!("{}{}", count, unit);
+            }
+        }
+        if result.is_empty() {
+            result += "0s";
+        }
+        let value = Value::String(result);
+        return Ok(value);
+    }
+}
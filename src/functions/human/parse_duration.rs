@@ -0,0 +1,56 @@
+use crate::function_executor::*;
+use super::duration::DURATION_UNITS;
+
+/// Function: `string:parse_duration`
+pub struct ParseDuration;
+
+impl FunctionExecutor for ParseDuration {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:parse_duration")
+            .with_description("Parses human-readable duration into count of seconds. Input: `string` with numbers followed by units `w`, `d`, `h`, `m` or `s`: `1h30m`. Returns `uint` count of seconds.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::UInt)
+            .with_example(FunctionExample::new("1h30m", "string:parse_duration()|uint:to_string()", "5400"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let error = || {
+            return FunctionError::msg(format!("Unable to parse duration `{}`", value));
+        };
+        if value.is_empty() {
+            return Err(error());
+        }
+        let mut result: u128 = 0;
+        let mut count: Option<u128> = None;
+        for character in value.chars() {
+            if let Some(digit) = character.to_digit(10) {
+                let next_count = count
+                    .unwrap_or(0)
+                    .checked_mul(10)
+                    .and_then(|count| {
+                        return count.checked_add(digit as u128);
+                    })
+                    .ok_or_else(error)?;
+                count = Some(next_count);
+                continue;
+            }
+            let unit_secs = DURATION_UNITS
+                .iter()
+                .find(|(unit, _)| *unit == character)
+                .map(|(_, unit_secs)| *unit_secs)
+                .ok_or_else(error)?;
+            let secs = count
+                .ok_or_else(error)?
+                .checked_mul(unit_secs)
+                .ok_or_else(error)?;
+            result = result.checked_add(secs).ok_or_else(error)?;
+            count = None;
+        }
+        if count.is_some() {
+            return Err(error());
+        }
+        let value = Value::UInt(result);
+        return Ok(value);
+    }
+}
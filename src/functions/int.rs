@@ -1,3 +1,4 @@
+use crate::function_executor::FunctionModule;
 mod abs;
 pub use self::abs::Abs;
 mod to_string;
@@ -5,4 +6,40 @@ pub use self::to_string::ToString;
 mod hex;
 pub use self::hex::Hex;
 mod hex_fmt;
-pub use self::hex_fmt::HexFmt;
\ No newline at end of file
+pub use self::hex_fmt::HexFmt;
+mod pad;
+pub use self::pad::Pad;
+mod add;
+pub use self::add::Add;
+mod sub;
+pub use self::sub::Sub;
+mod mul;
+pub use self::mul::Mul;
+mod div;
+pub use self::div::Div;
+mod rem;
+pub use self::rem::Rem;
+mod to_uint;
+pub use self::to_uint::ToUInt;
+mod to_float;
+pub use self::to_float::ToFloat;
+mod unwrap_or;
+pub use self::unwrap_or::UnwrapOr;
+
+/// Returns module with int functions.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("int")
+        .with_function(Abs)
+        .with_function(Hex)
+        .with_function(HexFmt)
+        .with_function(ToString)
+        .with_function(Pad)
+        .with_function(Add)
+        .with_function(Sub)
+        .with_function(Mul)
+        .with_function(Div)
+        .with_function(Rem)
+        .with_function(ToUInt)
+        .with_function(ToFloat)
+        .with_function(UnwrapOr);
+}
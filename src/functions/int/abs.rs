@@ -1,20 +1,21 @@
 use crate::function_executor::*;
 
 /// Function: `int:abs`
-/// 
-/// Input: `int`
-///
-/// Returns `uint`
 pub struct Abs;
 
 impl FunctionExecutor for Abs {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("int:abs")
+            .with_description("Computes absolute value of int. Returns `uint`.")
+            .with_input_type(ValueType::Int)
+            .with_output_type(ValueType::UInt)
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new(-5i32, "int:abs()|uint:to_string()", "5"))
     }
 
     fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
         let value = value.as_int()?;
-        let value = value.abs() as u128;
+        let value = value.unsigned_abs();
         let value = Value::UInt(value);
         return Ok(value);
     }
@@ -0,0 +1,31 @@
+use crate::function_executor::*;
+
+/// Function: `int:div`
+pub struct Div;
+
+impl FunctionExecutor for Div {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("int:div")
+            .with_description("Divides int by number. Returns `int` quotient. Overflow is an error.")
+            .with_input_type(ValueType::Int)
+            .with_output_type(ValueType::Int)
+            .with_argument(FunctionArgument::int().with_name("divisor"))
+            .with_output_shape(OutputShape::new("[0-9-]").with_min_length(1))
+            .with_example(FunctionExample::new(-7i32, "int:div(2int)|int:to_string()", "-3"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_int()?;
+        let argument = arguments[0].as_int()?;
+        if *argument == 0 {
+            return Err(FunctionError::msg("Division by zero"));
+        }
+        let value = value
+            .checked_div(*argument)
+            .ok_or_else(|| {
+                return FunctionError::msg(format!("Overflow when computing {} / {}", value, argument));
+            })?;
+        let value = Value::Int(value);
+        return Ok(value);
+    }
+}
@@ -1,15 +1,16 @@
 use crate::function_executor::*;
 
 /// Function: `int:hex`
-/// 
-/// Input: `int`
-///
-/// Returns `string`
 pub struct Hex;
 
 impl FunctionExecutor for Hex {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("int:hex")
+            .with_description("Formats int as lowercase hex, negative values in two's complement. Returns `string`.")
+            .with_input_type(ValueType::Int)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[0-9a-f]").with_min_length(1))
+            .with_example(FunctionExample::new(255i32, "int:hex()", "ff"))
     }
 
     fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
@@ -1,18 +1,17 @@
 use crate::function_executor::*;
 
 /// Function: `int:hex_fmt`
-/// 
-/// Input: `int`
-///
-/// * first argument: uint number of leading zeroes
-///
-/// Returns `string`
 pub struct HexFmt;
 
 impl FunctionExecutor for HexFmt {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("int:hex_fmt")
-            .with_argument(FunctionArgument::uint())
+            .with_description("Formats int as lowercase hex with leading zeroes, negative values in two's complement. Returns `string`.")
+            .with_input_type(ValueType::Int)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::uint().with_name("width").with_description("number of leading zeroes"))
+            .with_output_shape(OutputShape::new("[0-9a-f]").with_min_length(1).with_min_length_argument(0))
+            .with_example(FunctionExample::new(255i32, "int:hex_fmt(4uint)", "00ff"))
     }
 
     fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
@@ -0,0 +1,28 @@
+use crate::function_executor::*;
+
+/// Function: `int:mul`
+pub struct Mul;
+
+impl FunctionExecutor for Mul {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("int:mul")
+            .with_description("Multiplies int by number. Returns `int` product. Overflow is an error.")
+            .with_input_type(ValueType::Int)
+            .with_output_type(ValueType::Int)
+            .with_argument(FunctionArgument::int().with_name("operand"))
+            .with_output_shape(OutputShape::new("[0-9-]").with_min_length(1))
+            .with_example(FunctionExample::new(-5i32, "int:mul(3int)|int:to_string()", "-15"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_int()?;
+        let argument = arguments[0].as_int()?;
+        let value = value
+            .checked_mul(*argument)
+            .ok_or_else(|| {
+                return FunctionError::msg(format!("Overflow when computing {} * {}", value, argument));
+            })?;
+        let value = Value::Int(value);
+        return Ok(value);
+    }
+}
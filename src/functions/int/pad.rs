@@ -0,0 +1,30 @@
+use crate::function_executor::*;
+use crate::functions::format_width::read_format_width;
+
+/// Function: `int:pad`
+pub struct Pad;
+
+impl FunctionExecutor for Pad {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("int:pad")
+            .with_description("Formats int padded with leading zeroes. Returns decimal `string` padded with leading zeroes up to width. Sign is written before zeroes and counts into width: `-5` padded to 4 gives `-005`.")
+            .with_input_type(ValueType::Int)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::uint().with_name("width"))
+            .with_output_shape(OutputShape::new("[0-9-]").with_min_length(1).with_min_length_argument(0))
+            .with_example(FunctionExample::new(-5i32, "int:pad(4uint)", "-005"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_int()?;
+        let width = read_format_width(&arguments[0], "width")?;
+        let value = format!("{:0width$}", value, width = width);
+        let value = Value::String(value);
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let _ = read_format_width(&arguments[0], "width")?;
+        return Ok(());
+    }
+}
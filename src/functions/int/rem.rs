@@ -0,0 +1,31 @@
+use crate::function_executor::*;
+
+/// Function: `int:rem`
+pub struct Rem;
+
+impl FunctionExecutor for Rem {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("int:rem")
+            .with_description("Computes remainder of division of int by number. Returns `int` remainder. Overflow is an error.")
+            .with_input_type(ValueType::Int)
+            .with_output_type(ValueType::Int)
+            .with_argument(FunctionArgument::int().with_name("divisor"))
+            .with_output_shape(OutputShape::new("[0-9-]").with_min_length(1))
+            .with_example(FunctionExample::new(-7i32, "int:rem(2int)|int:to_string()", "-1"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_int()?;
+        let argument = arguments[0].as_int()?;
+        if *argument == 0 {
+            return Err(FunctionError::msg("Division by zero"));
+        }
+        let value = value
+            .checked_rem(*argument)
+            .ok_or_else(|| {
+                return FunctionError::msg(format!("Overflow when computing {} % {}", value, argument));
+            })?;
+        let value = Value::Int(value);
+        return Ok(value);
+    }
+}
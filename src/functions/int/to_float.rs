@@ -0,0 +1,20 @@
+use crate::function_executor::*;
+
+/// Function: `int:to_float`
+pub struct ToFloat;
+
+impl FunctionExecutor for ToFloat {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("int:to_float")
+            .with_description("Converts int into float. Returns `float`. Large values lose precision.")
+            .with_input_type(ValueType::Int)
+            .with_output_type(ValueType::Float)
+            .with_example(FunctionExample::new(-2i32, "int:to_float()|float:to_string()", "-2"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_int()?;
+        let value = Value::Float(*value as f64);
+        return Ok(value);
+    }
+}
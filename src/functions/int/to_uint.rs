@@ -0,0 +1,24 @@
+use crate::function_executor::*;
+use std::convert::TryFrom;
+
+/// Function: `int:to_uint`
+pub struct ToUInt;
+
+impl FunctionExecutor for ToUInt {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("int:to_uint")
+            .with_description("Converts int into uint. Returns `uint`. Negative value is an error.")
+            .with_input_type(ValueType::Int)
+            .with_output_type(ValueType::UInt)
+            .with_example(FunctionExample::new(5i32, "int:to_uint()|uint:to_string()", "5"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_int()?;
+        let value = u128::try_from(*value).map_err(|_| {
+            return FunctionError::msg(format!("Unable to convert int {} into uint", value));
+        })?;
+        let value = Value::UInt(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::function_executor::*;
+
+/// Function: `int:unwrap_or`
+pub struct UnwrapOr;
+
+impl FunctionExecutor for UnwrapOr {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("int:unwrap_or")
+            .with_description("Unwraps optional int, empty option gives default. Returns `int`.")
+            .with_input_type(ValueType::option(ValueType::Int))
+            .with_output_type(ValueType::Int)
+            .with_argument(FunctionArgument::int().with_name("default").with_description("default int"))
+            .with_example(FunctionExample::new(None::<i32>, "int:unwrap_or(-1int)|int:to_string()", "-1"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.into_option()?;
+        let value = match value {
+            Some(value) => {
+                value.into_int()?
+            },
+            None => {
+                *arguments[0].as_int()?
+            },
+        };
+        let value = Value::Int(value);
+        return Ok(value);
+    }
+}
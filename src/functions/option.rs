@@ -0,0 +1,18 @@
+use crate::function_executor::FunctionModule;
+mod map;
+pub use self::map::Map;
+mod unwrap_or_empty;
+pub use self::unwrap_or_empty::UnwrapOrEmpty;
+mod is_some;
+pub use self::is_some::IsSome;
+mod or;
+pub use self::or::Or;
+
+/// Returns module with option functions.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("option")
+        .with_function(Map)
+        .with_function(UnwrapOrEmpty)
+        .with_function(IsSome)
+        .with_function(Or);
+}
@@ -0,0 +1,20 @@
+use crate::function_executor::*;
+
+/// Function: `option:is_some`
+pub struct IsSome;
+
+impl FunctionExecutor for IsSome {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("option:is_some")
+            .with_description("Checks whether option contains value. Returns `bool`.")
+            .with_input_type(ValueType::option(ValueType::Any))
+            .with_output_type(ValueType::Bool)
+            .with_example(FunctionExample::new(Some(5u32), "option:is_some()|bool:to_string()", "true"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.into_option()?;
+        let value = Value::Bool(value.is_some());
+        return Ok(value);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::function_executor::*;
+
+/// Function: `option:map`
+pub struct Map;
+
+impl FunctionExecutor for Map {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("option:map")
+            .with_description("Applies function to value inside of option. Returns `Option<Any>`.")
+            .with_input_type(ValueType::option(ValueType::Any))
+            .with_output_type(ValueType::option(ValueType::Any))
+            .with_argument(FunctionArgument::function().with_name("function").with_description("function applied to inner value, e.g. `option:map(uint:hex())`"))
+            .with_example(FunctionExample::new(Some(255u32), "option:map(uint:hex())|string:unwrap_or('-')", "ff"))
+    }
+
+    fn call(&self, _value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        return Err(FunctionError::msg("Function `option:map` requires function context"));
+    }
+
+    fn call_with_context(&self, value: Value, _arguments: &[Value], context: &FunctionContext) -> Result<Value, FunctionError> {
+        let value = value.into_option()?;
+        let value = match value {
+            Some(value) => {
+                let value = context.call_argument(0, value)?;
+                Some(Box::new(value))
+            },
+            None => {
+                None
+            },
+        };
+        let value = Value::Option(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,31 @@
+use crate::function_executor::*;
+
+/// Function: `option:or`
+pub struct Or;
+
+impl FunctionExecutor for Or {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("option:or")
+            .with_description("Falls back to other field when option is empty. Returns input when it is some, otherwise value of fallback field as `Option<Any>`.")
+            .with_input_type(ValueType::option(ValueType::Any))
+            .with_output_type(ValueType::option(ValueType::Any))
+            .with_argument(FunctionArgument::field().with_name("fallback").with_description("fallback field, e.g. `option:or(self.nickname)`"))
+            .with_example(FunctionExample::new(vec![None, Some("login")], "option:or(self.1)|string:unwrap_or('-')", "login").with_field("self.0"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.into_option()?;
+        if let Some(value) = value {
+            return Ok(Value::Option(Some(Box::new(value))));
+        }
+        let value = match arguments[0] {
+            Value::Option(ref value) => {
+                Value::Option(value.clone())
+            },
+            ref value => {
+                Value::Option(Some(Box::new(value.clone())))
+            },
+        };
+        return Ok(value);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::function_executor::*;
+
+/// Function: `option:unwrap_or_empty`
+pub struct UnwrapOrEmpty;
+
+impl FunctionExecutor for UnwrapOrEmpty {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("option:unwrap_or_empty")
+            .with_description("Unwraps option, empty option gives empty string. Returns inner value or empty string.")
+            .with_input_type(ValueType::option(ValueType::Any))
+            .with_output_type(ValueType::Any)
+            .with_example(FunctionExample::new(None::<String>, "option:unwrap_or_empty()", ""))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.into_option()?;
+        let value = match value {
+            Some(value) => {
+                value
+            },
+            None => {
+                Value::String(String::new())
+            },
+        };
+        return Ok(value);
+    }
+}
@@ -0,0 +1,13 @@
+use crate::function_executor::FunctionModule;
+mod regex_cache;
+mod replace;
+pub use self::replace::Replace;
+mod capture;
+pub use self::capture::Capture;
+
+/// Returns module with regex functions.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("regex")
+        .with_function(Replace::new())
+        .with_function(Capture::new());
+}
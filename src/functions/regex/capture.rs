@@ -0,0 +1,62 @@
+use crate::function_executor::*;
+use super::regex_cache::RegexCache;
+use std::convert::TryFrom;
+
+/// Function: `regex:capture`
+pub struct Capture {
+    cache: RegexCache,
+}
+
+impl Capture {
+    /// Creates function with empty regex cache.
+    pub fn new() -> Capture {
+        return Capture {
+            cache: RegexCache::new(),
+        }
+    }
+}
+
+impl Default for Capture {
+    fn default() -> Self {
+        return Capture::new();
+    }
+}
+
+impl FunctionExecutor for Capture {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("regex:capture")
+            .with_description("Extracts capture group of the first regex match. Returns `Option<String>` with capture group of the first match.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::option(ValueType::String))
+            .with_argument(FunctionArgument::string().with_name("pattern").with_description("regex pattern"))
+            .with_argument(FunctionArgument::uint().with_name("group").with_description("index of capture group, `0` is the whole match"))
+            .with_example(FunctionExample::new("v12.3", "regex:capture('v(\\d+)', 1uint)|string:unwrap_or('-')", "12"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let regex = self.cache.get(&arguments[0])?;
+        let group = arguments[1].as_uint()?;
+        let group = usize::try_from(*group)?;
+        let value = regex
+            .captures(value)
+            .and_then(|captures| {
+                return captures.get(group);
+            })
+            .map(|capture| {
+                return Value::String(capture.as_str().to_string());
+            })
+            .map(Box::new);
+        let value = Value::Option(value);
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let regex = self.cache.get(&arguments[0])?;
+        let group = arguments[1].as_uint()?;
+        if *group >= regex.captures_len() as u128 {
+            return Err(FunctionError::msg(format!("Capture group `{}` not found", group)));
+        }
+        return Ok(());
+    }
+}
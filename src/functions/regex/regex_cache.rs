@@ -0,0 +1,29 @@
+use crate::function_executor::*;
+use regex::Regex;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Keeps regexes compiled when template is built, so they are not compiled on every call.
+pub struct RegexCache {
+    regexes: RefCell<HashMap<String, Regex>>,
+}
+
+impl RegexCache {
+    /// Creates empty cache.
+    pub fn new() -> RegexCache {
+        return RegexCache {
+            regexes: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// Returns compiled regex, compiling it on first use.
+    pub fn get(&self, pattern: &Value) -> Result<Regex, FunctionError> {
+        let pattern = pattern.as_string()?;
+        if let Some(regex) = self.regexes.borrow().get(pattern) {
+            return Ok(regex.clone());
+        }
+        let regex = Regex::new(pattern)?;
+        let _ = self.regexes.borrow_mut().insert(pattern.clone(), regex.clone());
+        return Ok(regex);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::function_executor::*;
+use super::regex_cache::RegexCache;
+
+/// Function: `regex:replace`
+pub struct Replace {
+    cache: RegexCache,
+}
+
+impl Replace {
+    /// Creates function with empty regex cache.
+    pub fn new() -> Replace {
+        return Replace {
+            cache: RegexCache::new(),
+        }
+    }
+}
+
+impl Default for Replace {
+    fn default() -> Self {
+        return Replace::new();
+    }
+}
+
+impl FunctionExecutor for Replace {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("regex:replace")
+            .with_description("Replaces all regex matches. Returns `String` with all matches replaced.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::string().with_name("pattern").with_description("regex pattern"))
+            .with_argument(FunctionArgument::string().with_name("replacement").with_description("replacement, `$1` or `${name}` refers to capture group"))
+            .with_example(FunctionExample::new("a1b22", "regex:replace('\\d+', '#')", "a#b#"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let regex = self.cache.get(&arguments[0])?;
+        let replacement = arguments[1].as_string()?;
+        let value = regex.replace_all(value, replacement.as_str());
+        let value = Value::String(value.into_owned());
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let _ = self.cache.get(&arguments[0])?;
+        return Ok(());
+    }
+}
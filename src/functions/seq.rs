@@ -0,0 +1,18 @@
+use crate::function_executor::FunctionModule;
+mod join;
+pub use self::join::Join;
+mod get;
+pub use self::get::Get;
+mod len;
+pub use self::len::Len;
+mod counter;
+pub use self::counter::Counter;
+
+/// Returns module with sequence functions.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("seq")
+        .with_function(Join)
+        .with_function(Get)
+        .with_function(Len)
+        .with_function(Counter);
+}
@@ -0,0 +1,30 @@
+use crate::function_executor::*;
+
+/// Function: `seq:counter`
+pub struct Counter;
+
+impl FunctionExecutor for Counter {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("seq:counter")
+            .with_description("Numbers values of batch. Input: any type, ignored. Returns `uint` number of call starting from `1`. Numbers increase across values of `Template::render_batch`, every placeholder has its own counter.")
+            .with_output_type(ValueType::UInt)
+            .with_state()
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new("a", "seq:counter()|uint:to_string()", "1"))
+    }
+
+    fn call(&self, _value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        return Err(FunctionError::msg("Function `seq:counter` requires function context"));
+    }
+
+    fn call_with_context(&self, _value: Value, _arguments: &[Value], context: &FunctionContext) -> Result<Value, FunctionError> {
+        let key = format!("seq:counter:{}", context.get_placeholder_index());
+        let mut state = context.get_batch_state();
+        let counter = match state.get(&key) {
+            Some(counter) => *counter.as_uint()? + 1,
+            None => 1,
+        };
+        let _ = state.set(key, Value::UInt(counter));
+        return Ok(Value::UInt(counter));
+    }
+}
@@ -0,0 +1,32 @@
+use crate::function_executor::*;
+
+/// Function: `seq:get`
+pub struct Get;
+
+impl FunctionExecutor for Get {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("seq:get")
+            .with_description("Takes item of sequence by index. Returns `Option` with item.")
+            .with_input_type(ValueType::seq(ValueType::Any))
+            .with_argument(FunctionArgument::int().with_name("index").with_description("index, negative index is counted from the end"))
+            .with_example(FunctionExample::new("a,b,c", "string:split(',')|seq:get(-1int)|string:unwrap_or('-')", "c"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let mut value = value.into_seq()?;
+        let index = arguments[0].as_int()?;
+        let length = value.len() as i128;
+        let index = if *index < 0 {
+            length + *index
+        } else {
+            *index
+        };
+        let value = if index >= 0 && index < length {
+            Some(Box::new(value.swap_remove(index as usize)))
+        } else {
+            None
+        };
+        let value = Value::Option(value);
+        return Ok(value);
+    }
+}
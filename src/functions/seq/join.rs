@@ -0,0 +1,45 @@
+use crate::function_executor::*;
+
+/// Function: `seq:join`
+pub struct Join;
+
+impl FunctionExecutor for Join {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("seq:join")
+            .with_description("Joins items of sequence with separator. Returns `String`. Items must be strings, chars or integers.")
+            .with_input_type(ValueType::seq(ValueType::Any))
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::string().with_name("separator"))
+            .with_example(FunctionExample::new(vec![1u32, 2, 3], "seq:join('-')", "1-2-3"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_seq()?;
+        let separator = arguments[0].as_string()?;
+        let mut result = String::new();
+        for (index, item) in value.iter().enumerate() {
+            if index > 0 {
+                result += separator;
+            }
+            match item {
+                Value::String(ref item) => {
+                    result += item;
+                },
+                Value::Char(item) => {
+                    result.push(*item);
+                },
+                Value::UInt(item) => {
+                    result += &item.to_string();
+                },
+                Value::Int(item) => {
+                    result += &item.to_string();
+                },
+                _ => {
+                    return Err(FunctionError::msg(format!("Unable to join {:?}", item)));
+                },
+            }
+        }
+        let value = Value::String(result);
+        return Ok(value);
+    }
+}
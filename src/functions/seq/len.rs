@@ -0,0 +1,21 @@
+use crate::function_executor::*;
+
+/// Function: `seq:len`
+pub struct Len;
+
+impl FunctionExecutor for Len {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("seq:len")
+            .with_description("Counts items of sequence. Returns `uint` count of items.")
+            .with_input_type(ValueType::seq(ValueType::Any))
+            .with_output_type(ValueType::UInt)
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new(vec!["a", "b"], "seq:len()|uint:to_string()", "2"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_seq()?;
+        let value = Value::UInt(value.len() as u128);
+        return Ok(value);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::function_executor::FunctionModule;
 mod unwrap_or;
 pub use self::unwrap_or::UnwrapOr;
 mod substr;
@@ -7,4 +8,99 @@ pub use self::trim_start::TrimStart;
 mod trim_end;
 pub use self::trim_end::TrimEnd;
 mod trim;
-pub use self::trim::Trim;
\ No newline at end of file
+pub use self::trim::Trim;
+mod decode_hex;
+pub use self::decode_hex::DecodeHex;
+mod decode_base64;
+pub use self::decode_base64::DecodeBase64;
+mod decode_base64url;
+pub use self::decode_base64url::DecodeBase64Url;
+mod decode_base32;
+pub use self::decode_base32::DecodeBase32;
+mod decode_base58;
+pub use self::decode_base58::DecodeBase58;
+mod lower;
+pub use self::lower::Lower;
+mod upper;
+pub use self::upper::Upper;
+mod snake_case;
+pub use self::snake_case::SnakeCase;
+mod kebab_case;
+pub use self::kebab_case::KebabCase;
+mod camel_case;
+pub use self::camel_case::CamelCase;
+mod title_case;
+pub use self::title_case::TitleCase;
+mod slug;
+pub use self::slug::Slug;
+mod measure;
+mod pad_left;
+pub use self::pad_left::PadLeft;
+mod pad_right;
+pub use self::pad_right::PadRight;
+mod center;
+pub use self::center::Center;
+mod truncate;
+pub use self::truncate::Truncate;
+mod chars;
+pub use self::chars::Chars;
+mod graphemes;
+pub use self::graphemes::Graphemes;
+mod replace;
+pub use self::replace::Replace;
+mod replace_n;
+pub use self::replace_n::ReplaceN;
+mod strip_prefix;
+pub use self::strip_prefix::StripPrefix;
+mod strip_suffix;
+pub use self::strip_suffix::StripSuffix;
+mod split;
+pub use self::split::Split;
+mod contains;
+pub use self::contains::Contains;
+mod starts_with;
+pub use self::starts_with::StartsWith;
+mod ends_with;
+pub use self::ends_with::EndsWith;
+mod dedupe;
+pub use self::dedupe::Dedupe;
+mod to_string;
+pub use self::to_string::ToString;
+
+/// Returns module with string functions.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("string")
+        .with_function(ToString)
+        .with_function(Trim)
+        .with_function(UnwrapOr)
+        .with_function(TrimStart)
+        .with_function(TrimEnd)
+        .with_function(SubStr)
+        .with_function(Chars)
+        .with_function(Graphemes)
+        .with_function(Lower)
+        .with_function(Upper)
+        .with_function(SnakeCase)
+        .with_function(KebabCase)
+        .with_function(CamelCase)
+        .with_function(TitleCase)
+        .with_function(Slug)
+        .with_function(PadLeft)
+        .with_function(PadRight)
+        .with_function(Center)
+        .with_function(Truncate)
+        .with_function(Replace)
+        .with_function(ReplaceN)
+        .with_function(StripPrefix)
+        .with_function(StripSuffix)
+        .with_function(Split)
+        .with_function(Contains)
+        .with_function(StartsWith)
+        .with_function(EndsWith)
+        .with_function(DecodeHex)
+        .with_function(DecodeBase64)
+        .with_function(DecodeBase64Url)
+        .with_function(DecodeBase32)
+        .with_function(DecodeBase58)
+        .with_function(Dedupe);
+}
@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+use heck::ToLowerCamelCase;
+
+/// Function: `string:camel_case`
+pub struct CamelCase;
+
+impl FunctionExecutor for CamelCase {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:camel_case")
+            .with_description("Converts string into camelCase. Returns `String` converted into `camelCase`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new("hello world", "string:camel_case()", "helloWorld"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = value.to_lower_camel_case();
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
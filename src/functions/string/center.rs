@@ -0,0 +1,38 @@
+use crate::function_executor::*;
+use super::measure::Measure;
+use crate::functions::format_width::read_format_width;
+
+/// Function: `string:center`
+pub struct Center;
+
+impl FunctionExecutor for Center {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:center")
+            .with_description("Pads string from both sides. Returns `String` padded from both sides with `fill` up to `width`. Extra unit of padding goes to the right.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::uint().with_name("width"))
+            .with_argument(FunctionArgument::string().with_name("fill"))
+            .with_argument(FunctionArgument::string().with_name("measure").with_description("measure: `chars`, `graphemes` or `width`"))
+            .with_example(FunctionExample::new("ab", "string:center(5uint, '*', 'chars')", "*ab**"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let width = read_format_width(&arguments[0], "width")?;
+        let fill = arguments[1].as_string()?;
+        let measure = Measure::parse(&arguments[2])?;
+        let padding = width.saturating_sub(measure.measure(value));
+        let mut result = measure.padding(fill, padding / 2)?;
+        result += value;
+        result += &measure.padding(fill, padding - padding / 2)?;
+        let value = Value::String(result);
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let _ = read_format_width(&arguments[0], "width")?;
+        let _ = Measure::parse(&arguments[2])?;
+        return Ok(());
+    }
+}
@@ -0,0 +1,33 @@
+use crate::function_executor::*;
+use super::measure::{Measure, select_units};
+
+/// Function: `string:chars`
+pub struct Chars;
+
+impl FunctionExecutor for Chars {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:chars")
+            .with_description("Takes range of chars. Returns `String`. Range is truncated to the bounds of input.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::int().with_name("offset").with_description("offset in chars, negative offset is counted from the end"))
+            .with_argument(FunctionArgument::uint().with_name("length").with_description("length in chars"))
+            .with_example(FunctionExample::new("hello", "string:chars(-3int, 2uint)", "ll"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let offset = arguments[0].as_int()?;
+        let length = arguments[1].as_uint()?;
+        let units: Vec<_> = Measure::Chars
+            .split(value)
+            .into_iter()
+            .map(|(unit, _)| {
+                return unit;
+            })
+            .collect();
+        let (begin, end) = select_units(&units, *offset, *length);
+        let value = Value::String(value[begin..end].to_string());
+        return Ok(value);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+
+/// Function: `string:contains`
+pub struct Contains;
+
+impl FunctionExecutor for Contains {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:contains")
+            .with_description("Checks whether string contains substring. Returns `bool`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::Bool)
+            .with_argument(FunctionArgument::string().with_name("pattern").with_description("to search"))
+            .with_example(FunctionExample::new("hello", "string:contains('ell')|bool:to_string()", "true"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let pattern = arguments[0].as_string()?;
+        let value = Value::Bool(value.contains(pattern.as_str()));
+        return Ok(value);
+    }
+}
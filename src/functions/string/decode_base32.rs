@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+use data_encoding::BASE32;
+
+/// Function: `string:decode_base32`
+pub struct DecodeBase32;
+
+impl FunctionExecutor for DecodeBase32 {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:decode_base32")
+            .with_description("Decodes base32 string into bytes. Input: `string` in standard padded base32. Returns decoded `bytes`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::Bytes)
+            .with_example(FunctionExample::new("NBUQ====", "string:decode_base32()|bytes:hex()", "6869"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = BASE32.decode(value.as_bytes())?;
+        let value = Value::Bytes(value);
+        return Ok(value);
+    }
+}
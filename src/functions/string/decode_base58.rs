@@ -0,0 +1,21 @@
+use crate::function_executor::*;
+
+/// Function: `string:decode_base58`
+pub struct DecodeBase58;
+
+impl FunctionExecutor for DecodeBase58 {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:decode_base58")
+            .with_description("Decodes base58 string into bytes. Input: `string` in base58 using Bitcoin alphabet. Returns decoded `bytes`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::Bytes)
+            .with_example(FunctionExample::new("8wr", "string:decode_base58()|bytes:hex()", "6869"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = bs58::decode(value).into_vec()?;
+        let value = Value::Bytes(value);
+        return Ok(value);
+    }
+}
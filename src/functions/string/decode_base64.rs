@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+use data_encoding::BASE64;
+
+/// Function: `string:decode_base64`
+pub struct DecodeBase64;
+
+impl FunctionExecutor for DecodeBase64 {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:decode_base64")
+            .with_description("Decodes base64 string into bytes. Input: `string` in standard padded base64. Returns decoded `bytes`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::Bytes)
+            .with_example(FunctionExample::new("aGk=", "string:decode_base64()|bytes:hex()", "6869"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = BASE64.decode(value.as_bytes())?;
+        let value = Value::Bytes(value);
+        return Ok(value);
+    }
+}
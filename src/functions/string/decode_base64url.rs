@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+use data_encoding::BASE64URL_NOPAD;
+
+/// Function: `string:decode_base64url`
+pub struct DecodeBase64Url;
+
+impl FunctionExecutor for DecodeBase64Url {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:decode_base64url")
+            .with_description("Decodes URL-safe base64 string into bytes. Input: `string` in URL-safe base64 without padding. Returns decoded `bytes`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::Bytes)
+            .with_example(FunctionExample::new("aGk", "string:decode_base64url()|bytes:hex()", "6869"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = BASE64URL_NOPAD.decode(value.as_bytes())?;
+        let value = Value::Bytes(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+use data_encoding::HEXLOWER_PERMISSIVE;
+
+/// Function: `string:decode_hex`
+pub struct DecodeHex;
+
+impl FunctionExecutor for DecodeHex {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:decode_hex")
+            .with_description("Decodes hex string into bytes. Input: `string` in hex, both lowercase and uppercase. Returns decoded `bytes`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::Bytes)
+            .with_example(FunctionExample::new("01AB", "string:decode_hex()|bytes:hex()", "01ab"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = HEXLOWER_PERMISSIVE.decode(value.as_bytes())?;
+        let value = Value::Bytes(value);
+        return Ok(value);
+    }
+}
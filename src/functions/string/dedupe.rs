@@ -0,0 +1,41 @@
+use crate::function_executor::*;
+
+/// Function: `string:dedupe`
+pub struct Dedupe;
+
+impl FunctionExecutor for Dedupe {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:dedupe")
+            .with_description("Makes strings of batch unique. Returns `String` unique across values of `Template::render_batch`: repeated string gets suffix `-1`, `-2` and so on. Every placeholder has its own set of strings.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_state()
+            .with_example(FunctionExample::new("report", "string:dedupe()", "report"))
+    }
+
+    fn call(&self, _value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        return Err(FunctionError::msg("Function `string:dedupe` requires function context"));
+    }
+
+    fn call_with_context(&self, value: Value, _arguments: &[Value], context: &FunctionContext) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let prefix = format!("string:dedupe:{}:", context.get_placeholder_index());
+        let mut state = context.get_batch_state();
+        let value_key = format!("{}{}", prefix, value);
+        let mut suffix = match state.get(&value_key) {
+            Some(suffix) => *suffix.as_uint()?,
+            None => {
+                let _ = state.set(value_key, Value::UInt(1));
+                return Ok(Value::String(value.clone()));
+            },
+        };
+        let mut result = format!("{}-{}", value, suffix);
+        while state.contains(&format!("{}{}", prefix, result)) {
+            suffix += 1;
+            result = format!("{}-{}", value, suffix);
+        }
+        let _ = state.set(value_key, Value::UInt(suffix + 1));
+        let _ = state.set(format!("{}{}", prefix, result), Value::UInt(1));
+        return Ok(Value::String(result));
+    }
+}
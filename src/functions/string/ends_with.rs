@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+
+/// Function: `string:ends_with`
+pub struct EndsWith;
+
+impl FunctionExecutor for EndsWith {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:ends_with")
+            .with_description("Checks whether string ends with suffix. Returns `bool`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::Bool)
+            .with_argument(FunctionArgument::string().with_name("suffix"))
+            .with_example(FunctionExample::new("file.json", "string:ends_with('.json')|bool:to_string()", "true"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let suffix = arguments[0].as_string()?;
+        let value = Value::Bool(value.ends_with(suffix.as_str()));
+        return Ok(value);
+    }
+}
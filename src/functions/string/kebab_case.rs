@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+use heck::ToKebabCase;
+
+/// Function: `string:kebab_case`
+pub struct KebabCase;
+
+impl FunctionExecutor for KebabCase {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:kebab_case")
+            .with_description("Converts string into kebab-case. Returns `String` converted into `kebab-case`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new("Hello World", "string:kebab_case()", "hello-world"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = value.to_kebab_case();
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,21 @@
+use crate::function_executor::*;
+
+/// Function: `string:lower`
+pub struct Lower;
+
+impl FunctionExecutor for Lower {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:lower")
+            .with_description("Converts string into lowercase. Returns `String` converted into lowercase.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new("Hello", "string:lower()", "hello"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = value.to_lowercase();
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::function_executor::*;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+use crate::functions::format_width::MAX_FORMAT_WIDTH;
+use std::convert::TryFrom;
+
+/// Unit used for measuring string length: `chars`, `graphemes` or `width`.
+pub enum Measure {
+    /// Unicode scalar values.
+    Chars,
+    /// Extended grapheme clusters.
+    Graphemes,
+    /// Display width of grapheme clusters: wide characters take 2 columns, combining marks take 0.
+    Width,
+}
+
+impl Measure {
+    /// Parses measure from function argument.
+    pub fn parse(value: &Value) -> Result<Measure, FunctionError> {
+        let value = value.as_string()?;
+        match value.as_str() {
+            "chars" => {
+                return Ok(Measure::Chars);
+            },
+            "graphemes" => {
+                return Ok(Measure::Graphemes);
+            },
+            "width" => {
+                return Ok(Measure::Width);
+            },
+            _ => {
+                return Err(FunctionError::msg(format!("Unknown measure `{}`, expected `chars`, `graphemes` or `width`", value)));
+            },
+        }
+    }
+
+    /// Splits string into units with their lengths.
+    pub fn split<'a>(&self, value: &'a str) -> Vec<(&'a str, usize)> {
+        match self {
+            Measure::Chars => {
+                return value
+                    .char_indices()
+                    .map(|(index, character)| {
+                        return (&value[index..index + character.len_utf8()], 1);
+                    })
+                    .collect();
+            },
+            Measure::Graphemes => {
+                return value
+                    .graphemes(true)
+                    .map(|grapheme| {
+                        return (grapheme, 1);
+                    })
+                    .collect();
+            },
+            Measure::Width => {
+                return value
+                    .graphemes(true)
+                    .map(|grapheme| {
+                        return (grapheme, grapheme.width());
+                    })
+                    .collect();
+            },
+        }
+    }
+
+    /// Returns length of string as sum of lengths of its units, so it is consistent with `Measure::split`.
+    pub fn measure(&self, value: &str) -> usize {
+        return self
+            .split(value)
+            .into_iter()
+            .map(|(_, length)| {
+                return length;
+            })
+            .sum();
+    }
+
+    /// Returns the longest prefix of string which length does not exceed `max_length`, and its length.
+    pub fn take<'a>(&self, value: &'a str, max_length: usize) -> (&'a str, usize) {
+        let mut end = 0;
+        let mut length = 0;
+        for (unit, unit_length) in self.split(value) {
+            if length + unit_length > max_length {
+                break;
+            }
+            end += unit.len();
+            length += unit_length;
+        }
+        return (&value[..end], length);
+    }
+
+    /// Repeats `fill` until padding of `length` is produced.
+    ///
+    /// Padding may be shorter than `length` when the last unit of `fill` does not fit.
+    /// Padding longer than `MAX_FORMAT_WIDTH` units is rejected.
+    pub fn padding(&self, fill: &str, length: usize) -> Result<String, FunctionError> {
+        if length > MAX_FORMAT_WIDTH {
+            return Err(FunctionError::msg(format!("Padding must not exceed {}, got {}", MAX_FORMAT_WIDTH, length)));
+        }
+        let fill_length = self.measure(fill);
+        if fill_length == 0 {
+            return Err(FunctionError::msg("Fill string must not be empty"));
+        }
+        let mut result = fill.repeat(length / fill_length);
+        let (rest, _) = self.take(fill, length % fill_length);
+        result += rest;
+        return Ok(result);
+    }
+}
+
+/// Selects units of string starting from `offset` and returns their byte range.
+///
+/// Negative `offset` is counted from the end of string. Range is truncated to the bounds of string.
+pub fn select_units(units: &[&str], offset: i128, length: u128) -> (usize, usize) {
+    let count = units.len() as i128;
+    let begin = if offset < 0 {
+        count.saturating_add(offset).max(0)
+    } else {
+        offset.min(count)
+    };
+    let end = begin.saturating_add(i128::try_from(length).unwrap_or(i128::MAX)).min(count);
+    let byte_begin: usize = units[..begin as usize].iter().map(|unit| unit.len()).sum();
+    let byte_end: usize = byte_begin + units[begin as usize..end as usize].iter().map(|unit| unit.len()).sum::<usize>();
+    return (byte_begin, byte_end);
+}
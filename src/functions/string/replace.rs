@@ -0,0 +1,25 @@
+use crate::function_executor::*;
+
+/// Function: `string:replace`
+pub struct Replace;
+
+impl FunctionExecutor for Replace {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:replace")
+            .with_description("Replaces all occurrences of substring. Returns `String` with all occurrences replaced.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::string().with_name("pattern").with_description("to search"))
+            .with_argument(FunctionArgument::string().with_name("replacement"))
+            .with_example(FunctionExample::new("a-b-c", "string:replace('-', '/')", "a/b/c"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let from = arguments[0].as_string()?;
+        let to = arguments[1].as_string()?;
+        let value = value.replace(from.as_str(), to);
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,29 @@
+use crate::function_executor::*;
+use std::convert::TryFrom;
+
+/// Function: `string:replace_n`
+pub struct ReplaceN;
+
+impl FunctionExecutor for ReplaceN {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:replace_n")
+            .with_description("Replaces first occurrences of substring. Returns `String` with first occurrences replaced.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::string().with_name("pattern").with_description("to search"))
+            .with_argument(FunctionArgument::string().with_name("replacement"))
+            .with_argument(FunctionArgument::uint().with_name("count").with_description("maximal number of replacements"))
+            .with_example(FunctionExample::new("a-b-c", "string:replace_n('-', '/', 1uint)", "a/b-c"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let from = arguments[0].as_string()?;
+        let to = arguments[1].as_string()?;
+        let count = arguments[2].as_uint()?;
+        let count = usize::try_from(*count).unwrap_or(usize::MAX);
+        let value = value.replacen(from.as_str(), to, count);
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::function_executor::*;
+use deunicode::deunicode;
+
+/// Function: `string:slug`
+pub struct Slug;
+
+impl FunctionExecutor for Slug {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:slug")
+            .with_description("Converts string into URL slug. Returns `String` transliterated into lowercase ASCII letters and digits, any run of other characters is replaced with single `-`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[a-z0-9-]"))
+            .with_example(FunctionExample::new("Hello, World!", "string:slug()", "hello-world"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = deunicode(value);
+        let mut result = String::with_capacity(value.len());
+        let mut is_separator_pending = false;
+        for character in value.chars() {
+            if character.is_ascii_alphanumeric() {
+                if is_separator_pending && !result.is_empty() {
+                    result.push('-');
+                }
+                is_separator_pending = false;
+                result.push(character.to_ascii_lowercase());
+            } else {
+                is_separator_pending = true;
+            }
+        }
+        let value = Value::String(result);
+        return Ok(value);
+    }
+}
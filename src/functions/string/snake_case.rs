@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+use heck::ToSnakeCase;
+
+/// Function: `string:snake_case`
+pub struct SnakeCase;
+
+impl FunctionExecutor for SnakeCase {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:snake_case")
+            .with_description("Converts string into snake_case. Returns `String` converted into `snake_case`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new("Hello World", "string:snake_case()", "hello_world"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = value.to_snake_case();
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
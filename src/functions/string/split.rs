@@ -0,0 +1,28 @@
+use crate::function_executor::*;
+
+/// Function: `string:split`
+pub struct Split;
+
+impl FunctionExecutor for Split {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:split")
+            .with_description("Splits string by separator. Returns `Seq<String>`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::seq(ValueType::String))
+            .with_argument(FunctionArgument::string().with_name("separator"))
+            .with_example(FunctionExample::new("a,b", "string:split(',')|seq:join('/')", "a/b"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let separator = arguments[0].as_string()?;
+        let value = value
+            .split(separator.as_str())
+            .map(|item| {
+                return Value::String(item.to_string());
+            })
+            .collect();
+        let value = Value::Seq(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+
+/// Function: `string:starts_with`
+pub struct StartsWith;
+
+impl FunctionExecutor for StartsWith {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:starts_with")
+            .with_description("Checks whether string starts with prefix. Returns `bool`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::Bool)
+            .with_argument(FunctionArgument::string().with_name("prefix"))
+            .with_example(FunctionExample::new("v1.2", "string:starts_with('v')|bool:to_string()", "true"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let prefix = arguments[0].as_string()?;
+        let value = Value::Bool(value.starts_with(prefix.as_str()));
+        return Ok(value);
+    }
+}
@@ -0,0 +1,26 @@
+use crate::function_executor::*;
+
+/// Function: `string:strip_prefix`
+pub struct StripPrefix;
+
+impl FunctionExecutor for StripPrefix {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:strip_prefix")
+            .with_description("Removes prefix from string. Returns `String` without prefix. String that does not start with prefix is returned as is.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::string().with_name("prefix"))
+            .with_example(FunctionExample::new("v1.2", "string:strip_prefix('v')", "1.2"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.into_string()?;
+        let prefix = arguments[0].as_string()?;
+        let value = match value.strip_prefix(prefix.as_str()) {
+            Some(stripped) => stripped.to_string(),
+            None => value,
+        };
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,26 @@
+use crate::function_executor::*;
+
+/// Function: `string:strip_suffix`
+pub struct StripSuffix;
+
+impl FunctionExecutor for StripSuffix {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:strip_suffix")
+            .with_description("Removes suffix from string. Returns `String` without suffix. String that does not end with suffix is returned as is.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::string().with_name("suffix"))
+            .with_example(FunctionExample::new("file.json", "string:strip_suffix('.json')", "file"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.into_string()?;
+        let suffix = arguments[0].as_string()?;
+        let value = match value.strip_suffix(suffix.as_str()) {
+            Some(stripped) => stripped.to_string(),
+            None => value,
+        };
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
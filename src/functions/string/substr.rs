@@ -1,20 +1,17 @@
 use crate::function_executor::*;
 
 /// Function: `string:substr`
-/// 
-/// Input: `String`
-///
-/// * first argument: uint offset
-/// * second argument: uint length
-///
-/// Returns `Option<String>`
 pub struct SubStr;
 
 impl FunctionExecutor for SubStr {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("string:substr")
-            .with_argument(FunctionArgument::uint())
-            .with_argument(FunctionArgument::uint())
+            .with_description("Takes range of bytes of string. Returns `Option<String>`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::option(ValueType::String))
+            .with_argument(FunctionArgument::uint().with_name("offset"))
+            .with_argument(FunctionArgument::uint().with_name("length"))
+            .with_example(FunctionExample::new("hello", "string:substr(1uint, 3uint)|string:unwrap_or('-')", "ell"))
     }
 
     fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
@@ -23,7 +20,7 @@ impl FunctionExecutor for SubStr {
         let begin = *begin as usize;
         let length = arguments[1].as_uint()?;
         let length = *length as usize;
-        let end = begin + length;
+        let end = begin.saturating_add(length);
         let value = value
             .get(begin..end)
             .map(|value| {
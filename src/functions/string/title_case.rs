@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+use heck::ToTitleCase;
+
+/// Function: `string:title_case`
+pub struct TitleCase;
+
+impl FunctionExecutor for TitleCase {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:title_case")
+            .with_description("Converts string into Title Case. Returns `String` converted into `Title Case`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new("hello world", "string:title_case()", "Hello World"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = value.to_title_case();
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
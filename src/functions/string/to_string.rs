@@ -0,0 +1,20 @@
+use crate::function_executor::*;
+
+/// Function: `string:to_string`
+pub struct ToString;
+
+impl FunctionExecutor for ToString {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:to_string")
+            .with_description("Returns string as is, lets generic `to_string` accept strings. Returns `string`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new("hi", "string:to_string()", "hi"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = Value::String(value.clone());
+        return Ok(value);
+    }
+}
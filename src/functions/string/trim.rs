@@ -1,15 +1,15 @@
 use crate::function_executor::*;
 
 /// Function: `string:trim`
-/// 
-/// Input: `String`
-///
-/// Returns `String`
 pub struct Trim;
 
 impl FunctionExecutor for Trim {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("string:trim")
+            .with_description("Removes leading and trailing whitespace. Returns `String`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new(" a ", "string:trim()", "a"))
     }
 
     fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
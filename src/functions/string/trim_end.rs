@@ -1,15 +1,15 @@
 use crate::function_executor::*;
 
 /// Function: `string:trim_end`
-/// 
-/// Input: `String`
-///
-/// Returns `String`
 pub struct TrimEnd;
 
 impl FunctionExecutor for TrimEnd {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("string:trim_end")
+            .with_description("Removes trailing whitespace. Returns `String`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new(" a ", "string:trim_end()", " a"))
     }
 
     fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
@@ -1,15 +1,15 @@
 use crate::function_executor::*;
 
 /// Function: `string:trim_start`
-/// 
-/// Input: `String`
-///
-/// Returns `String`
 pub struct TrimStart;
 
 impl FunctionExecutor for TrimStart {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("string:trim_start")
+            .with_description("Removes leading whitespace. Returns `String`.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new(" a ", "string:trim_start()", "a "))
     }
 
     fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
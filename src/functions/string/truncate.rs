@@ -0,0 +1,42 @@
+use crate::function_executor::*;
+use super::measure::Measure;
+use std::convert::TryFrom;
+
+/// Function: `string:truncate`
+pub struct Truncate;
+
+impl FunctionExecutor for Truncate {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:truncate")
+            .with_description("Truncates string to max length. Returns `String` which length does not exceed max length, ellipsis included.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::uint().with_name("max_length"))
+            .with_argument(FunctionArgument::string().with_name("ellipsis").with_description("ellipsis appended to truncated string"))
+            .with_argument(FunctionArgument::string().with_name("measure").with_description("measure: `chars`, `graphemes` or `width`"))
+            .with_example(FunctionExample::new("hello world", "string:truncate(8uint, '...', 'chars')", "hello..."))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let max_length = arguments[0].as_uint()?;
+        let max_length = usize::try_from(*max_length)?;
+        let ellipsis = arguments[1].as_string()?;
+        let measure = Measure::parse(&arguments[2])?;
+        if measure.measure(value) <= max_length {
+            let value = Value::String(value.clone());
+            return Ok(value);
+        }
+        let (ellipsis, ellipsis_length) = measure.take(ellipsis, max_length);
+        let (prefix, _) = measure.take(value, max_length - ellipsis_length);
+        let mut result = prefix.to_string();
+        result += ellipsis;
+        let value = Value::String(result);
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let _ = Measure::parse(&arguments[2])?;
+        return Ok(());
+    }
+}
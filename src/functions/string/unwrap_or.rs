@@ -1,18 +1,16 @@
 use crate::function_executor::*;
 
 /// Function: `string:unwrap_or`
-/// 
-/// Input: `Option<String>`
-///
-/// * first argument: default string
-///
-/// Returns `String`
 pub struct UnwrapOr;
 
 impl FunctionExecutor for UnwrapOr {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("string:unwrap_or")
-            .with_argument(FunctionArgument::string())
+            .with_description("Unwraps optional string, empty option gives default. Returns `String`.")
+            .with_input_type(ValueType::option(ValueType::String))
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::string().with_name("default").with_description("default string"))
+            .with_example(FunctionExample::new(None::<String>, "string:unwrap_or('-')", "-"))
     }
 
     fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
@@ -0,0 +1,21 @@
+use crate::function_executor::*;
+
+/// Function: `string:upper`
+pub struct Upper;
+
+impl FunctionExecutor for Upper {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("string:upper")
+            .with_description("Converts string into uppercase. Returns `String` converted into uppercase.")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new("Hello", "string:upper()", "HELLO"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = value.to_uppercase();
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::function_executor::FunctionModule;
+mod date_time;
+mod format;
+pub use self::format::Format;
+mod format_with_offset;
+pub use self::format_with_offset::FormatWithOffset;
+mod from_unix_secs;
+pub use self::from_unix_secs::FromUnixSecs;
+mod from_unix_millis;
+pub use self::from_unix_millis::FromUnixMillis;
+mod with_offset;
+pub use self::with_offset::WithOffset;
+mod year;
+pub use self::year::Year;
+mod month;
+pub use self::month::Month;
+mod day;
+pub use self::day::Day;
+mod iso_week;
+pub use self::iso_week::IsoWeek;
+
+/// Returns module with date and time functions.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("time")
+        .with_function(Format)
+        .with_function(FormatWithOffset)
+        .with_function(FromUnixSecs)
+        .with_function(FromUnixMillis)
+        .with_function(WithOffset)
+        .with_function(Year)
+        .with_function(Month)
+        .with_function(Day)
+        .with_function(IsoWeek);
+}
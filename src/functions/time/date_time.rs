@@ -0,0 +1,89 @@
+use crate::function_executor::*;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Offset, SecondsFormat, Utc};
+use chrono::format::{Item, StrftimeItems};
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+const NAIVE_DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+const NAIVE_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Reads date and time from unix timestamp in seconds or from string.
+///
+/// Supported strings: RFC 3339 (`2024-05-17T10:00:00+03:00`),
+/// date and time without offset (`2024-05-17T10:00:00`), date (`2024-05-17`).
+/// Values without offset are read as UTC.
+pub fn read_date_time(value: &Value) -> Result<DateTime<FixedOffset>, FunctionError> {
+    match value {
+        Value::UInt(..) | Value::Int(..) => {
+            let value = read_timestamp(value)?;
+            return from_unix_secs(value);
+        },
+        Value::String(value) => {
+            if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+                return Ok(date_time);
+            }
+            if let Ok(date_time) = NaiveDateTime::parse_from_str(value, NAIVE_DATE_TIME_FORMAT) {
+                return Ok(date_time.and_utc().fixed_offset());
+            }
+            if let Ok(date) = NaiveDate::parse_from_str(value, NAIVE_DATE_FORMAT) {
+                let date_time = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+                return Ok(date_time.and_utc().fixed_offset());
+            }
+            return Err(FunctionError::msg(format!("Unable to read date and time from {:?}", value)));
+        },
+        _ => {
+            return Err(FunctionError::msg(format!("Trying to cast {:?} as date and time", value)));
+        },
+    }
+}
+
+/// Reads unix timestamp from `uint` or `int`.
+pub fn read_timestamp(value: &Value) -> Result<i64, FunctionError> {
+    match value {
+        Value::UInt(value) => {
+            return Ok(i64::try_from(*value)?);
+        },
+        Value::Int(value) => {
+            return Ok(i64::try_from(*value)?);
+        },
+        _ => {
+            return Err(FunctionError::msg(format!("Trying to cast {:?} as unix timestamp", value)));
+        },
+    }
+}
+
+/// Creates date and time from unix timestamp in seconds.
+pub fn from_unix_secs(value: i64) -> Result<DateTime<FixedOffset>, FunctionError> {
+    let value = DateTime::<Utc>::from_timestamp(value, 0)
+        .ok_or_else(|| {
+            return FunctionError::msg(format!("Unix timestamp {} is out of range", value));
+        })?;
+    return Ok(value.fixed_offset());
+}
+
+/// Reads timezone offset: `Z`, `UTC`, `+03:00`, `-0530`.
+pub fn read_offset(value: &str) -> Result<FixedOffset, FunctionError> {
+    if value == "Z" || value == "UTC" {
+        return Ok(Utc.fix());
+    }
+    let offset = FixedOffset::from_str(value)
+        .map_err(|_| {
+            return FunctionError::msg(format!("Unable to read timezone offset from {:?}", value));
+        })?;
+    return Ok(offset);
+}
+
+/// Reads `strftime` format: `%Y/%m/%d`.
+pub fn read_format(format: &str) -> Result<Vec<Item<'_>>, FunctionError> {
+    let items: Vec<_> = StrftimeItems::new(format).collect();
+    if items.contains(&Item::Error) {
+        return Err(FunctionError::msg(format!("Invalid date and time format {:?}", format)));
+    }
+    return Ok(items);
+}
+
+/// Converts date and time into RFC 3339 string.
+pub fn write_date_time(value: DateTime<FixedOffset>) -> Value {
+    let value = value.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+    return Value::String(value);
+}
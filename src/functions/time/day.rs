@@ -0,0 +1,23 @@
+use crate::function_executor::*;
+use super::date_time::read_date_time;
+use chrono::Datelike;
+
+/// Function: `time:day`
+pub struct Day;
+
+impl FunctionExecutor for Day {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("time:day")
+            .with_description("Returns day of month. Input: date and time: `uint`, `int` unix timestamp in seconds or `string`. Returns `uint` day of month.")
+            .with_output_type(ValueType::UInt)
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new(1700000000u32, "time:day()|uint:to_string()", "14"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = read_date_time(&value)?;
+        let value = value.day() as u128;
+        let value = Value::UInt(value);
+        return Ok(value);
+    }
+}
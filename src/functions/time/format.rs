@@ -0,0 +1,28 @@
+use crate::function_executor::*;
+use super::date_time::{read_date_time, read_format};
+
+/// Function: `time:format`
+pub struct Format;
+
+impl FunctionExecutor for Format {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("time:format")
+            .with_description("Formats date and time in its own offset. Input: date and time: `uint`, `int` unix timestamp in seconds or `string`. Returns `string`.")
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::string().with_name("format").with_description("`strftime` format, for example `'%Y/%m/%d'`"))
+            .with_example(FunctionExample::new(1700000000u32, "time:format('%Y/%m/%d')", "2023/11/14"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = read_date_time(&value)?;
+        let items = read_format(arguments[0].as_string()?)?;
+        let value = value.format_with_items(items.into_iter()).to_string();
+        let value = Value::String(value);
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let _ = read_format(arguments[0].as_string()?)?;
+        return Ok(());
+    }
+}
@@ -0,0 +1,34 @@
+use crate::function_executor::*;
+use super::date_time::{read_date_time, read_format, read_offset};
+
+/// Function: `time:format`
+pub struct FormatWithOffset;
+
+impl FunctionExecutor for FormatWithOffset {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("time:format")
+            .with_description("Formats date and time in passed timezone offset. Input: date and time: `uint`, `int` unix timestamp in seconds or `string`. Returns `string`.")
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::string().with_name("format").with_description("`strftime` format, for example `'%Y/%m/%d'`"))
+            .with_argument(FunctionArgument::string().with_name("offset").with_description("timezone offset, for example `'+03:00'` or `'Z'`"))
+            .with_example(FunctionExample::new(1700000000u32, "time:format('%Y/%m/%d %H:%M', '+03:00')", "2023/11/15 01:13"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = read_date_time(&value)?;
+        let items = read_format(arguments[0].as_string()?)?;
+        let offset = read_offset(arguments[1].as_string()?)?;
+        let value = value
+            .with_timezone(&offset)
+            .format_with_items(items.into_iter())
+            .to_string();
+        let value = Value::String(value);
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let _ = read_format(arguments[0].as_string()?)?;
+        let _ = read_offset(arguments[1].as_string()?)?;
+        return Ok(());
+    }
+}
@@ -0,0 +1,25 @@
+use crate::function_executor::*;
+use super::date_time::{read_timestamp, write_date_time};
+use chrono::{DateTime, Utc};
+
+/// Function: `time:from_unix_millis`
+pub struct FromUnixMillis;
+
+impl FunctionExecutor for FromUnixMillis {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("time:from_unix_millis")
+            .with_description("Converts unix timestamp in milliseconds into date and time. Input: `uint` or `int` unix timestamp in milliseconds. Returns RFC 3339 `string` in UTC.")
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new(1700000000000u64, "time:from_unix_millis()", "2023-11-14T22:13:20Z"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = read_timestamp(&value)?;
+        let value = DateTime::<Utc>::from_timestamp_millis(value)
+            .ok_or_else(|| {
+                return FunctionError::msg(format!("Unix timestamp {} is out of range", value));
+            })?;
+        let value = write_date_time(value.fixed_offset());
+        return Ok(value);
+    }
+}
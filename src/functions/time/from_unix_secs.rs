@@ -0,0 +1,21 @@
+use crate::function_executor::*;
+use super::date_time::{from_unix_secs, read_timestamp, write_date_time};
+
+/// Function: `time:from_unix_secs`
+pub struct FromUnixSecs;
+
+impl FunctionExecutor for FromUnixSecs {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("time:from_unix_secs")
+            .with_description("Converts unix timestamp in seconds into date and time. Input: `uint` or `int` unix timestamp in seconds. Returns RFC 3339 `string` in UTC.")
+            .with_output_type(ValueType::String)
+            .with_example(FunctionExample::new(1700000000u32, "time:from_unix_secs()", "2023-11-14T22:13:20Z"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = read_timestamp(&value)?;
+        let value = from_unix_secs(value)?;
+        let value = write_date_time(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,23 @@
+use crate::function_executor::*;
+use super::date_time::read_date_time;
+use chrono::Datelike;
+
+/// Function: `time:iso_week`
+pub struct IsoWeek;
+
+impl FunctionExecutor for IsoWeek {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("time:iso_week")
+            .with_description("Returns ISO 8601 week number. Input: date and time: `uint`, `int` unix timestamp in seconds or `string`. Returns `uint` ISO 8601 week number.")
+            .with_output_type(ValueType::UInt)
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new(1700000000u32, "time:iso_week()|uint:to_string()", "46"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = read_date_time(&value)?;
+        let value = value.iso_week().week() as u128;
+        let value = Value::UInt(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::function_executor::*;
+use super::date_time::{read_date_time, read_offset, write_date_time};
+
+/// Function: `time:with_offset`
+pub struct WithOffset;
+
+impl FunctionExecutor for WithOffset {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("time:with_offset")
+            .with_description("Converts date and time into timezone offset. Input: date and time: `uint`, `int` unix timestamp in seconds or `string`. Returns RFC 3339 `string` with passed offset.")
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::string().with_name("offset").with_description("timezone offset, for example `'+03:00'` or `'Z'`"))
+            .with_example(FunctionExample::new(1700000000u32, "time:with_offset('+03:00')", "2023-11-15T01:13:20+03:00"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = read_date_time(&value)?;
+        let offset = read_offset(arguments[0].as_string()?)?;
+        let value = value.with_timezone(&offset);
+        let value = write_date_time(value);
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let _ = read_offset(arguments[0].as_string()?)?;
+        return Ok(());
+    }
+}
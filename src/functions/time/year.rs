@@ -0,0 +1,28 @@
+use crate::function_executor::*;
+use super::date_time::read_date_time;
+use chrono::Datelike;
+use std::convert::TryFrom;
+
+/// Function: `time:year`
+pub struct Year;
+
+impl FunctionExecutor for Year {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("time:year")
+            .with_description("Returns year. Input: date and time: `uint`, `int` unix timestamp in seconds or `string`. Returns `uint` year, fails for years before year 0.")
+            .with_output_type(ValueType::UInt)
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new(1700000000u32, "time:year()|uint:to_string()", "2023"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = read_date_time(&value)?;
+        let year = value.year();
+        let value = u128::try_from(year)
+            .map_err(|_| {
+                return FunctionError::msg(format!("Year {} is before year 0 and can not be returned as uint", year));
+            })?;
+        let value = Value::UInt(value);
+        return Ok(value);
+    }
+}
@@ -1,6 +1,54 @@
+use crate::function_executor::FunctionModule;
 mod to_string;
 pub use self::to_string::ToString;
 mod hex;
 pub use self::hex::Hex;
 mod hex_fmt;
-pub use self::hex_fmt::HexFmt;
\ No newline at end of file
+pub use self::hex_fmt::HexFmt;
+mod pad;
+pub use self::pad::Pad;
+mod radix;
+pub use self::radix::Radix;
+mod octal;
+pub use self::octal::Octal;
+mod binary;
+pub use self::binary::Binary;
+mod thousands;
+pub use self::thousands::Thousands;
+mod add;
+pub use self::add::Add;
+mod sub;
+pub use self::sub::Sub;
+mod mul;
+pub use self::mul::Mul;
+mod div;
+pub use self::div::Div;
+mod rem;
+pub use self::rem::Rem;
+mod to_int;
+pub use self::to_int::ToInt;
+mod to_float;
+pub use self::to_float::ToFloat;
+mod unwrap_or;
+pub use self::unwrap_or::UnwrapOr;
+
+/// Returns module with uint functions.
+pub fn module() -> FunctionModule {
+    return FunctionModule::new("uint")
+        .with_function(Hex)
+        .with_function(HexFmt)
+        .with_function(ToString)
+        .with_function(Pad)
+        .with_function(Radix)
+        .with_function(Octal)
+        .with_function(Binary)
+        .with_function(Thousands)
+        .with_function(Add)
+        .with_function(Sub)
+        .with_function(Mul)
+        .with_function(Div)
+        .with_function(Rem)
+        .with_function(ToInt)
+        .with_function(ToFloat)
+        .with_function(UnwrapOr);
+}
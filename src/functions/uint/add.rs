@@ -0,0 +1,28 @@
+use crate::function_executor::*;
+
+/// Function: `uint:add`
+pub struct Add;
+
+impl FunctionExecutor for Add {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:add")
+            .with_description("Adds number to uint. Returns `uint` sum. Overflow is an error.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::UInt)
+            .with_argument(FunctionArgument::uint().with_name("operand"))
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new(5u32, "uint:add(2uint)|uint:to_string()", "7"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let argument = arguments[0].as_uint()?;
+        let value = value
+            .checked_add(*argument)
+            .ok_or_else(|| {
+                return FunctionError::msg(format!("Overflow when computing {} + {}", value, argument));
+            })?;
+        let value = Value::UInt(value);
+        return Ok(value);
+    }
+}
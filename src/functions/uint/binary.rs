@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+
+/// Function: `uint:binary`
+pub struct Binary;
+
+impl FunctionExecutor for Binary {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:binary")
+            .with_description("Formats uint in binary. Returns `string`.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[01]").with_min_length(1))
+            .with_example(FunctionExample::new(5u32, "uint:binary()", "101"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let value = format!("{:b}", value);
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,31 @@
+use crate::function_executor::*;
+
+/// Function: `uint:div`
+pub struct Div;
+
+impl FunctionExecutor for Div {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:div")
+            .with_description("Divides uint by number. Returns `uint` quotient. Overflow is an error.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::UInt)
+            .with_argument(FunctionArgument::uint().with_name("divisor"))
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new(7u32, "uint:div(2uint)|uint:to_string()", "3"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let argument = arguments[0].as_uint()?;
+        if *argument == 0 {
+            return Err(FunctionError::msg("Division by zero"));
+        }
+        let value = value
+            .checked_div(*argument)
+            .ok_or_else(|| {
+                return FunctionError::msg(format!("Overflow when computing {} / {}", value, argument));
+            })?;
+        let value = Value::UInt(value);
+        return Ok(value);
+    }
+}
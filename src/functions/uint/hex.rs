@@ -1,15 +1,16 @@
 use crate::function_executor::*;
 
 /// Function: `uint:hex`
-/// 
-/// Input: `uint`
-///
-/// Returns `string`
 pub struct Hex;
 
 impl FunctionExecutor for Hex {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("uint:hex")
+            .with_description("Formats uint as lowercase hex. Returns `string`.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[0-9a-f]").with_min_length(1))
+            .with_example(FunctionExample::new(255u32, "uint:hex()", "ff"))
     }
 
     fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
@@ -1,18 +1,17 @@
 use crate::function_executor::*;
 
 /// Function: `uint:hex_fmt`
-/// 
-/// Input: `uint`
-///
-/// * first argument: uint number of leading zeroes
-///
-/// Returns `string`
 pub struct HexFmt;
 
 impl FunctionExecutor for HexFmt {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("uint:hex_fmt")
-            .with_argument(FunctionArgument::uint())
+            .with_description("Formats uint as lowercase hex with leading zeroes. Returns `string`.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::uint().with_name("width").with_description("number of leading zeroes"))
+            .with_output_shape(OutputShape::new("[0-9a-f]").with_min_length(1).with_min_length_argument(0))
+            .with_example(FunctionExample::new(255u32, "uint:hex_fmt(4uint)", "00ff"))
     }
 
     fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
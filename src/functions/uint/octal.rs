@@ -0,0 +1,22 @@
+use crate::function_executor::*;
+
+/// Function: `uint:octal`
+pub struct Octal;
+
+impl FunctionExecutor for Octal {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:octal")
+            .with_description("Formats uint in octal. Returns `string`.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[0-7]").with_min_length(1))
+            .with_example(FunctionExample::new(8u32, "uint:octal()", "10"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let value = format!("{:o}", value);
+        let value = Value::String(value);
+        return Ok(value);
+    }
+}
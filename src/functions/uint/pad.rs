@@ -0,0 +1,30 @@
+use crate::function_executor::*;
+use crate::functions::format_width::read_format_width;
+
+/// Function: `uint:pad`
+pub struct Pad;
+
+impl FunctionExecutor for Pad {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:pad")
+            .with_description("Formats uint padded with leading zeroes. Returns decimal `string` padded with leading zeroes up to width.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::uint().with_name("width"))
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1).with_min_length_argument(0))
+            .with_example(FunctionExample::new(7u32, "uint:pad(3uint)", "007"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let width = read_format_width(&arguments[0], "width")?;
+        let value = format!("{:0width$}", value, width = width);
+        let value = Value::String(value);
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let _ = read_format_width(&arguments[0], "width")?;
+        return Ok(());
+    }
+}
@@ -0,0 +1,50 @@
+use crate::function_executor::*;
+
+/// Function: `uint:radix`
+pub struct Radix;
+
+impl FunctionExecutor for Radix {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:radix")
+            .with_description("Formats uint in passed base. Returns `string` with lowercase digits.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::uint().with_name("base").with_description("base from 2 to 36"))
+            .with_output_shape(OutputShape::new("[0-9a-z]").with_min_length(1))
+            .with_example(FunctionExample::new(35u32, "uint:radix(36uint)", "z"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let base = arguments[0].as_uint()?;
+        let base = read_base(*base)?;
+        let mut value = *value;
+        let mut result = Vec::new();
+        loop {
+            result.push(DIGITS[(value % base) as usize]);
+            value /= base;
+            if value == 0 {
+                break;
+            }
+        }
+        result.reverse();
+        let value = String::from_utf8(result)?;
+        let value = Value::String(value);
+        return Ok(value);
+    }
+
+    fn validate_arguments(&self, arguments: &[Value]) -> Result<(), FunctionError> {
+        let base = arguments[0].as_uint()?;
+        let _ = read_base(*base)?;
+        return Ok(());
+    }
+}
+
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+fn read_base(base: u128) -> Result<u128, FunctionError> {
+    if !(2..=36).contains(&base) {
+        return Err(FunctionError::msg(format!("Base must be from 2 to 36, got {}", base)));
+    }
+    return Ok(base);
+}
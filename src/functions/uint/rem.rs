@@ -0,0 +1,31 @@
+use crate::function_executor::*;
+
+/// Function: `uint:rem`
+pub struct Rem;
+
+impl FunctionExecutor for Rem {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:rem")
+            .with_description("Computes remainder of division of uint by number. Returns `uint` remainder. Overflow is an error.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::UInt)
+            .with_argument(FunctionArgument::uint().with_name("divisor"))
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new(7u32, "uint:rem(2uint)|uint:to_string()", "1"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let argument = arguments[0].as_uint()?;
+        if *argument == 0 {
+            return Err(FunctionError::msg("Division by zero"));
+        }
+        let value = value
+            .checked_rem(*argument)
+            .ok_or_else(|| {
+                return FunctionError::msg(format!("Overflow when computing {} % {}", value, argument));
+            })?;
+        let value = Value::UInt(value);
+        return Ok(value);
+    }
+}
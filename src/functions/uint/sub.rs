@@ -0,0 +1,28 @@
+use crate::function_executor::*;
+
+/// Function: `uint:sub`
+pub struct Sub;
+
+impl FunctionExecutor for Sub {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:sub")
+            .with_description("Subtracts number from uint. Returns `uint` difference. Overflow is an error.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::UInt)
+            .with_argument(FunctionArgument::uint().with_name("operand"))
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new(7u32, "uint:sub(2uint)|uint:to_string()", "5"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let argument = arguments[0].as_uint()?;
+        let value = value
+            .checked_sub(*argument)
+            .ok_or_else(|| {
+                return FunctionError::msg(format!("Overflow when computing {} - {}", value, argument));
+            })?;
+        let value = Value::UInt(value);
+        return Ok(value);
+    }
+}
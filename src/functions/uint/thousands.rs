@@ -0,0 +1,30 @@
+use crate::function_executor::*;
+
+/// Function: `uint:thousands`
+pub struct Thousands;
+
+impl FunctionExecutor for Thousands {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:thousands")
+            .with_description("Formats uint with grouped digits. Returns decimal `string` with digits grouped by three.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::String)
+            .with_argument(FunctionArgument::string().with_name("separator"))
+            .with_example(FunctionExample::new(1234567u32, "uint:thousands(',')", "1,234,567"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let separator = arguments[0].as_string()?;
+        let digits = value.to_string();
+        let mut result = String::new();
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index) % 3 == 0 {
+                result += separator;
+            }
+            result.push(digit);
+        }
+        let value = Value::String(result);
+        return Ok(value);
+    }
+}
@@ -0,0 +1,20 @@
+use crate::function_executor::*;
+
+/// Function: `uint:to_float`
+pub struct ToFloat;
+
+impl FunctionExecutor for ToFloat {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:to_float")
+            .with_description("Converts uint into float. Returns `float`. Large values lose precision.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::Float)
+            .with_example(FunctionExample::new(2u32, "uint:to_float()|float:to_string()", "2"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let value = Value::Float(*value as f64);
+        return Ok(value);
+    }
+}
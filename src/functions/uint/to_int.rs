@@ -0,0 +1,24 @@
+use crate::function_executor::*;
+use std::convert::TryFrom;
+
+/// Function: `uint:to_int`
+pub struct ToInt;
+
+impl FunctionExecutor for ToInt {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:to_int")
+            .with_description("Converts uint into int. Returns `int`. Value that does not fit into int is an error.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::Int)
+            .with_example(FunctionExample::new(2u32, "uint:to_int()|int:to_string()", "2"))
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let value = i128::try_from(*value).map_err(|_| {
+            return FunctionError::msg(format!("Unable to convert uint {} into int", value));
+        })?;
+        let value = Value::Int(value);
+        return Ok(value);
+    }
+}
@@ -1,15 +1,16 @@
 use crate::function_executor::*;
 
 /// Function: `uint:to_string`
-/// 
-/// Input: `uint`
-///
-/// Returns `string`
 pub struct ToString;
 
 impl FunctionExecutor for ToString {
     fn schema(&self) -> FunctionSchema {
         FunctionSchema::new("uint:to_string")
+            .with_description("Converts uint into string. Returns `string`.")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::String)
+            .with_output_shape(OutputShape::new("[0-9]").with_min_length(1))
+            .with_example(FunctionExample::new(5u32, "uint:to_string()", "5"))
     }
 
     fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
@@ -0,0 +1,29 @@
+use crate::function_executor::*;
+
+/// Function: `uint:unwrap_or`
+pub struct UnwrapOr;
+
+impl FunctionExecutor for UnwrapOr {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:unwrap_or")
+            .with_description("Unwraps optional uint, empty option gives default. Returns `uint`.")
+            .with_input_type(ValueType::option(ValueType::UInt))
+            .with_output_type(ValueType::UInt)
+            .with_argument(FunctionArgument::uint().with_name("default").with_description("default uint"))
+            .with_example(FunctionExample::new(None::<u32>, "uint:unwrap_or(3uint)|uint:to_string()", "3"))
+    }
+
+    fn call(&self, value: Value, arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.into_option()?;
+        let value = match value {
+            Some(value) => {
+                value.into_uint()?
+            },
+            None => {
+                *arguments[0].as_uint()?
+            },
+        };
+        let value = Value::UInt(value);
+        return Ok(value);
+    }
+}
@@ -6,7 +6,25 @@
 //! 
 //! * Path navigation: `{self}`, `{self.field_1.field_2}`, `{self.0}`
 //! * Value processing: `{self | uint:hex() | string:substr(0uint, 2uint)|string:unwrap_or('--')}`
+//! * Generic functions dispatching on input type: `{self.x | to_string()}`, `{self.name | unwrap_or('--')}`
+//! * Option combinators with field and function arguments: `{self.id | option:map(uint:hex()) | unwrap_or('-')}`, `{self.name | option:or(self.login) | unwrap_or('')}`
+//! * Function modules: `TemplateBuilder::new_empty().with_module(functions::string::module())`, `without_namespace("debug")`, `with_alias("x", "uint:hex")`
+//! * Function reference: `TemplateBuilder::new().functions()` lists descriptions, argument names and examples, exported by `to_markdown()` and `to_json()`
+//! * Function context: executors overriding `call_with_context` read other fields, context variables of `Template::serialize_with_context(&value, &TemplateContext::new().with_variable("root", "/srv"))` and path of placeholder
+//! * Stateful functions: `Template::render_batch(&values)` shares state across items, `{self | seq:counter()}` numbers items, `{self.name | string:dedupe()}` appends `-1`, `-2` to repeated names
+//! * RFC 6570 URI templates (levels 1-4): `Template::parse_uri_template("/users/{id}{?fields*}")`
+//! * Matching produced strings: `Template::to_glob()` and `Template::to_regex()`
+//! * Partial application: `Template::bind(&partial_value)` folds known fields into text
+//! * Prefix listing: `Template::static_prefix()` and `Template::static_segments()`
+//! * Template inclusion: `{> template_name}` within `TemplateRegistry`
 //! 
+//! ## Cargo features
+//!
+//! * `time`: date and time functions `time:format('%Y/%m/%d')`, `time:from_unix_secs()`, `time:with_offset('+03:00')` and others.
+//! * `hash`: hashing functions `hash:sha256()`, `hash:md5()`, `hash:crc32()`, `hash:xxh3()`, `hash:fnv1a()`.
+//! * `regex`: regex functions `regex:replace('(\d+)', '#$1')`, `regex:capture('v(\d+)', 1uint)`. Patterns are compiled when template is built.
+//! * `macros`: attribute macro `#[template_function(name = "uint:neg")]` creating function executor from rust function, `template!("dir/{self.id}.json")` macro checking template syntax when crate is compiled and `#[derive(OnelineTemplate)]` formatting structures without serialization.
+//!
 //! ## Example
 //!
 //!```rust
@@ -70,10 +88,60 @@
 //!   assert_eq!("directory/file_no_fffffffffffffffffffffffffffffffe.json", &path);
 //! }
 //! ```
+//!
+//! Small helpers can be registered as closures. Schema is derived from types of closure:
+//! first parameter is input value, other parameters are arguments.
+//!
+//! ```rust
+//! use oneline_template::template::TemplateBuilder;
+//!
+//! fn main() {
+//!   let template = TemplateBuilder::new()
+//!     .with_fn("string:repeat", |value: String, count: usize| -> String {
+//!       return value.repeat(count);
+//!     })
+//!     .build("{ self | string:repeat(3uint) }")
+//!     .expect("Unable to create template");
+//!   let value = template.serialize(&"ab").expect("Unable to format value");
+//!   assert_eq!("ababab", &value);
+//! }
+//! ```
 
 /// Template engine.
 pub mod template;
 /// Traits and structures for function execution.
 pub mod function_executor;
 /// Library of functions.
-pub mod functions;
\ No newline at end of file
+///
+/// Description, arguments and examples of every function are part of its schema:
+/// `TemplateBuilder::new().functions().to_markdown()`.
+pub mod functions;
+/// Attribute macro creating function executor from rust function.
+#[cfg(feature = "macros")]
+pub use oneline_template_macros::template_function;
+/// Macro checking syntax of template when crate is compiled.
+///
+/// Creates `LazyTemplate` built with default functions when it is used first time.
+///
+/// ```rust
+/// use oneline_template::template;
+///
+/// let template = template!("dir/{self | uint:hex()}.json");
+/// assert_eq!(template.serialize(&255u32).unwrap(), "dir/ff.json");
+/// ```
+///
+/// Syntax errors are reported as compile errors:
+///
+/// ```rust,compile_fail
+/// use oneline_template::template;
+///
+/// let template = template!("dir/{self | uint:hex(}.json");
+/// ```
+#[cfg(feature = "macros")]
+pub use oneline_template_macros::template;
+/// Derive macro implementing `OnelineTemplate` for structure.
+#[cfg(feature = "macros")]
+pub use oneline_template_macros::OnelineTemplate;
+/// Structure formatted by template declared with `#[derive(OnelineTemplate)]`.
+#[cfg(feature = "macros")]
+pub use crate::template::OnelineTemplate;
@@ -1,11 +1,29 @@
-mod syntax;
+// Template parser is shared with `oneline-template-macros`, so templates are checked by the same code.
+use oneline_template_syntax as syntax;
 mod template_builder;
 pub use self::template_builder::TemplateBuilder;
 /// Module contains errors for `TemplateBuilder` 
 pub mod template_build_error;
 mod build_error;
+#[allow(clippy::module_inception)]
 mod template;
 pub use self::template::Template;
+mod template_context;
+pub use self::template_context::TemplateContext;
+mod lazy_template;
+pub use self::lazy_template::LazyTemplate;
+mod field_values;
+pub use self::field_values::FieldValues;
+mod oneline_template;
+pub use self::oneline_template::OnelineTemplate;
+mod template_registry;
+pub use self::template_registry::TemplateRegistry;
+mod template_registry_builder;
+pub use self::template_registry_builder::TemplateRegistryBuilder;
 mod argument_types_differ_error;
+mod function_set;
+mod function_overload;
+mod overwrite_policy;
+pub use self::overwrite_policy::OverwritePolicy;
 /// Module contains errors for `Template` 
 pub mod template_error;
\ No newline at end of file
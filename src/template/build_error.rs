@@ -1,5 +1,6 @@
 use crate::template::syntax::syntax_parse_error::SyntaxParseError as ParseError;
 use crate::template::argument_types_differ_error::ArgumentTypesDifferError;
+use crate::function_executor::{FunctionError, ValueType};
 use std::error::Error;
 use std::fmt;
 
@@ -14,6 +15,18 @@ pub enum BuildError {
     ArgumentsLengthDiffer(String),
     /// Error when passed argument type is differ from required. 
     ArgumentTypeNotMatch(ArgumentTypesDifferError),
+    /// Error when function has no overload for type of value returned by previous function.
+    InputTypeNotMatch(String, ValueType),
+    /// Error when function rejected arguments declared within template.
+    InvalidArguments(String, FunctionError),
+    /// Error when included template is not registered within template registry.
+    TemplateNotFound(String),
+    /// Error when templates include each other. Contains chain of template names.
+    IncludeCycle(Vec<String>),
+    /// Error when building template registered within template registry.
+    InvalidTemplate(String, Box<BuildError>),
+    /// Error when function with the same name, input type and argument types is registered twice with `OverwritePolicy::Error`.
+    FunctionOverwritten(String),
 }
 
 impl From<ParseError> for BuildError {
@@ -43,6 +56,24 @@ impl fmt::Display for BuildError {
             BuildError::ArgumentTypeNotMatch(ref error) => {
                 write!(f, "Argument with index `{}` at function `{}` has wrong type", error.get_argument_position(), error.get_function_name())
             },
+            BuildError::InputTypeNotMatch(ref function_name, ref input_type) => {
+                write!(f, "Function `{}` does not accept input of type `{}`", function_name, input_type)
+            },
+            BuildError::InvalidArguments(ref function_name, ref error) => {
+                write!(f, "Function `{}` rejected arguments: {}", function_name, error)
+            },
+            BuildError::TemplateNotFound(ref template_name) => {
+                write!(f, "Template with name `{}` not found", template_name)
+            },
+            BuildError::IncludeCycle(ref template_names) => {
+                write!(f, "Templates include each other: `{}`", template_names.join(" -> "))
+            },
+            BuildError::InvalidTemplate(ref template_name, ref error) => {
+                write!(f, "Template `{}`: {}", template_name, error)
+            },
+            BuildError::FunctionOverwritten(ref function_name) => {
+                write!(f, "Function `{}` is already registered", function_name)
+            },
         }
     }
 }
@@ -62,6 +93,24 @@ impl Error for BuildError {
             BuildError::ArgumentTypeNotMatch(..) => {
                 None
             },
+            BuildError::InputTypeNotMatch(..) => {
+                None
+            },
+            BuildError::InvalidArguments(..) => {
+                None
+            },
+            BuildError::TemplateNotFound(..) => {
+                None
+            },
+            BuildError::IncludeCycle(..) => {
+                None
+            },
+            BuildError::InvalidTemplate(_, ref error) => {
+                Some(error.as_ref())
+            },
+            BuildError::FunctionOverwritten(..) => {
+                None
+            },
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::function_executor::Value;
+
+/// Provides values of fields read by template without serialization. See `Template::render`.
+///
+/// Implemented by `#[derive(OnelineTemplate)]` (feature `macros`).
+pub trait FieldValues {
+    /// Returns value of field by path: `["user", "id"]` for `{self.user.id}`, `["0"]` for `{self.0}`.
+    ///
+    /// Returns `None` when field is missing.
+    fn field_value(&self, path: &[&str]) -> Option<Value>;
+}
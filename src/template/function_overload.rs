@@ -0,0 +1,64 @@
+use crate::function_executor::{FunctionExecutor, FunctionArgumentType, ValueType};
+use std::rc::Rc;
+
+/// Function executor registered in `FunctionSet` with namespace it was registered under.
+///
+/// Input type and argument types are taken from schema once, so resolving overload does not build schema.
+#[derive(Clone)]
+pub struct FunctionOverload {
+    function_executor: Rc<dyn FunctionExecutor>,
+    namespace: Option<String>,
+    input_type: Option<ValueType>,
+    argument_types: Vec<FunctionArgumentType>,
+    uses_state: bool,
+}
+
+impl FunctionOverload {
+    pub fn new(function_executor: Rc<dyn FunctionExecutor>, namespace: Option<String>) -> FunctionOverload {
+        let schema = function_executor.schema();
+        let input_type = schema.get_input_type().cloned();
+        let argument_types = schema
+            .get_arguments()
+            .iter()
+            .map(|argument| {
+                return argument.get_type().clone();
+            })
+            .collect();
+        let uses_state = schema.uses_state();
+        return FunctionOverload {
+            function_executor,
+            namespace,
+            input_type,
+            argument_types,
+            uses_state,
+        }
+    }
+
+    pub fn get_function_executor(&self) -> &Rc<dyn FunctionExecutor> {
+        return &self.function_executor;
+    }
+
+    /// Returns name of module for functions added by `TemplateBuilder::with_module`,
+    /// otherwise prefix of function name before `:`.
+    pub fn get_namespace(&self) -> Option<&str> {
+        return self.namespace.as_deref();
+    }
+
+    pub fn get_input_type(&self) -> Option<&ValueType> {
+        return self.input_type.as_ref();
+    }
+
+    pub fn uses_state(&self) -> bool {
+        return self.uses_state;
+    }
+
+    /// Checks whether overloads have the same input type and argument types.
+    pub fn has_signature_of(&self, overload: &FunctionOverload) -> bool {
+        return self.input_type == overload.input_type && self.argument_types == overload.argument_types;
+    }
+
+    /// Checks whether overload accepts arguments of passed types.
+    pub fn accepts_arguments(&self, argument_types: &[FunctionArgumentType]) -> bool {
+        return self.argument_types == argument_types;
+    }
+}
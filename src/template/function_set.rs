@@ -0,0 +1,144 @@
+use crate::function_executor::{FunctionExecutor, FunctionArgumentType, FunctionContext, Value, ValueType};
+use crate::template::template_error::TemplateError;
+use crate::template::function_overload::FunctionOverload;
+use std::rc::Rc;
+
+/// Overloads of function registered under the same name.
+pub struct FunctionSet {
+    overloads: Vec<FunctionOverload>,
+}
+
+impl FunctionSet {
+    pub fn new() -> FunctionSet {
+        return FunctionSet {
+            overloads: Vec::new(),
+        }
+    }
+
+    /// Adds overload. Overload with the same input type and argument types is replaced.
+    pub fn insert(&mut self, overload: FunctionOverload) {
+        let position = self
+            .overloads
+            .iter()
+            .position(|existing_overload| {
+                return existing_overload.has_signature_of(&overload);
+            });
+        match position {
+            Some(position) => {
+                self.overloads[position] = overload;
+            },
+            None => {
+                self.overloads.push(overload);
+            },
+        }
+    }
+
+    /// Removes overloads registered under passed namespace.
+    pub fn remove_namespace(&mut self, namespace: &str) {
+        self.overloads.retain(|overload| {
+            return overload.get_namespace() != Some(namespace);
+        });
+    }
+
+    /// Checks whether any overload is stateful. See `FunctionSchema::with_state`.
+    pub fn uses_state(&self) -> bool {
+        return self
+            .overloads
+            .iter()
+            .any(FunctionOverload::uses_state);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.overloads.is_empty();
+    }
+
+    /// Checks whether overload with the same input type and argument types is registered.
+    pub fn contains_signature_of(&self, overload: &FunctionOverload) -> bool {
+        return self
+            .overloads
+            .iter()
+            .any(|existing_overload| {
+                return existing_overload.has_signature_of(overload);
+            });
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=&dyn FunctionExecutor> {
+        return self
+            .overloads
+            .iter()
+            .map(|overload| {
+                return overload.get_function_executor().as_ref();
+            });
+    }
+
+    pub fn overloads(&self) -> impl Iterator<Item=&Rc<dyn FunctionExecutor>> {
+        return self
+            .overloads
+            .iter()
+            .map(FunctionOverload::get_function_executor);
+    }
+
+    /// Returns overloads that accept arguments of passed types.
+    pub fn resolve_arguments(&self, argument_types: &[FunctionArgumentType]) -> Vec<&dyn FunctionExecutor> {
+        return self
+            .resolve_overloads(argument_types)
+            .into_iter()
+            .map(|overload| {
+                return overload.get_function_executor().as_ref();
+            })
+            .collect();
+    }
+
+    fn resolve_overloads(&self, argument_types: &[FunctionArgumentType]) -> Vec<&FunctionOverload> {
+        return self
+            .overloads
+            .iter()
+            .filter(|overload| {
+                return overload.accepts_arguments(argument_types);
+            })
+            .collect();
+    }
+
+    /// Calls overload that accepts input value and arguments.
+    ///
+    /// Overloads with declared input type take precedence over overloads that accept any value.
+    pub fn call(
+        &self,
+        function_name: &str,
+        input: Value,
+        argument_types: &[FunctionArgumentType],
+        arguments: &[Value],
+        context: &FunctionContext,
+    ) -> Result<Value, TemplateError> {
+        let overloads = self.resolve_overloads(argument_types);
+        let overload = if overloads.len() == 1 {
+            Some(overloads[0])
+        } else {
+            let typed_overload = overloads
+                .iter()
+                .find(|overload| {
+                    return match overload.get_input_type() {
+                        Some(input_type) => input_type.matches(&input),
+                        None => false,
+                    };
+                });
+            let untyped_overload = overloads
+                .iter()
+                .find(|overload| {
+                    return overload.get_input_type().is_none();
+                });
+            typed_overload.or(untyped_overload).copied()
+        };
+        let overload = match overload {
+            Some(overload) => overload,
+            None => {
+                let input_type = ValueType::of(&input);
+                return Err(TemplateError::OverloadNotFound(function_name.to_string(), input_type.to_string()));
+            },
+        };
+        let value = overload
+            .get_function_executor()
+            .call_with_context(input, arguments, context)?;
+        return Ok(value);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::template::build_error::BuildError;
+use crate::template::template::Template;
+use crate::template::template_builder::TemplateBuilder;
+use std::cell::OnceCell;
+use std::ops::Deref;
+
+/// Template that is built with default functions when it is used first time.
+///
+/// Created by `template!` macro (feature `macros`) that checks syntax of template when crate is compiled.
+/// Template is not `Sync`, so use `thread_local!` for keeping it in static variable.
+pub struct LazyTemplate {
+    format: &'static str,
+    template: OnceCell<Template>,
+}
+
+impl LazyTemplate {
+    /// Creates lazy template. Template is built on first call of `LazyTemplate::get`.
+    pub const fn new(format: &'static str) -> LazyTemplate {
+        return LazyTemplate {
+            format,
+            template: OnceCell::new(),
+        }
+    }
+
+    /// Returns format of template.
+    pub fn get_format(&self) -> &'static str {
+        return self.format;
+    }
+
+    /// Returns template building it when it is called first time, returns error if template can not be built.
+    ///
+    /// Template that failed to build is not cached, so it is built again on next call.
+    pub fn try_get(&self) -> Result<&Template, BuildError> {
+        if let Some(template) = self.template.get() {
+            return Ok(template);
+        }
+        let template = TemplateBuilder::new().build(self.format)?;
+        return Ok(self.template.get_or_init(|| template));
+    }
+
+    /// Returns template building it when it is called first time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if template can not be built, e.g. when it calls unknown function.
+    pub fn get(&self) -> &Template {
+        return self.try_get().unwrap_or_else(|error| {
+            panic!("Unable to build template `{}`: {}", self.format, error);
+        });
+    }
+}
+
+impl Deref for LazyTemplate {
+    type Target = Template;
+
+    fn deref(&self) -> &Template {
+        return self.get();
+    }
+}
@@ -0,0 +1,33 @@
+use crate::template::field_values::FieldValues;
+use crate::template::template::TemplateError;
+
+/// Structure formatted by template declared with `#[derive(OnelineTemplate)]` (feature `macros`).
+///
+/// ```rust,ignore
+/// #[derive(OnelineTemplate)]
+/// #[template("dir/{self.id | uint:hex()}.json")]
+/// struct File {
+///     id: u32,
+/// }
+///
+/// assert_eq!(File {id: 255}.render(), "dir/ff.json");
+/// ```
+pub trait OnelineTemplate: FieldValues {
+    /// Returns template format.
+    fn template_format() -> &'static str;
+
+    /// Formats structure by template, returns error if template can not be built or function returns error.
+    fn try_render(&self) -> Result<String, TemplateError>;
+
+    /// Formats structure by template.
+    ///
+    /// # Panics
+    ///
+    /// Panics if template can not be built or function returns error. Function names are not checked
+    /// when crate is compiled, use `try_render` to handle unknown function as error.
+    fn render(&self) -> String {
+        return self.try_render().unwrap_or_else(|error| {
+            panic!("Unable to render template `{}`: {}", Self::template_format(), error);
+        });
+    }
+}
@@ -0,0 +1,10 @@
+/// Defines what happens when function with the same name, input type and argument types is registered again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverwritePolicy {
+    /// Registered function replaces existing one. Default policy.
+    Replace,
+    /// Existing function is kept, registered function is ignored.
+    Keep,
+    /// Building template fails with `TemplateBuildError::FunctionOverwritten`.
+    Error,
+}
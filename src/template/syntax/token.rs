@@ -1,7 +0,0 @@
-use crate::template::syntax::template_token::TemplateToken;
-
-#[derive(Debug)]
-pub enum Token {
-    Text(String),
-    Template(TemplateToken),
-}
\ No newline at end of file
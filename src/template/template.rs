@@ -1,37 +1,53 @@
-use crate::function_executor::Value;
+use crate::function_executor::{Value, FunctionContext, FunctionError};
+use crate::template::syntax::function_call_argument_token::FunctionCallArgumentToken;
+use crate::template::syntax::field_path::FieldPath;
 use crate::template::syntax::function_call_token::FunctionCallToken;
 use crate::template::syntax::token::Token;
 use crate::template::syntax::syntax::Syntax;
 use crate::template::syntax::field_read_token::FieldReadToken;
-use crate::template::syntax::field::Field;
-use crate::function_executor::FunctionExecutor;
+use crate::template::function_set::FunctionSet;
 use crate::template::TemplateBuilder;
 use crate::template::template_build_error::TemplateBuildError;
+use crate::template::field_values::FieldValues;
+use crate::template::template_context::TemplateContext;
+use crate::function_executor::FunctionState;
 use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
 use std::fmt;
 use std::any::type_name;
 use unstructured::Document;
 use serde::Serialize;
 mod convert_document;
-use self::convert_document::convert_document;
 mod convert_function_argument;
-use self::convert_function_argument::convert_function_argument;
+pub (crate) use self::convert_function_argument::{convert_function_argument, function_argument_type};
 mod convert_value_to_string;
 use self::convert_value_to_string::convert_value_to_string;
+mod select_document;
+use self::select_document::select_document;
+mod expand_uri_expression;
+use self::expand_uri_expression::expand_uri_expression;
+mod pattern;
+use self::pattern::{escape_glob, escape_regex, CaptureGroupNames};
 mod template_error;
 pub use self::template_error::TemplateError;
+mod field_source;
+use self::field_source::{FieldSource, FieldValuesSource, is_document_bound};
+mod render_state;
+use self::render_state::RenderState;
 
 
 /// Templates the passed structure. 
+#[derive(Clone)]
 pub struct Template {
     syntax: Syntax,
-    functions: HashMap<String, Box<dyn FunctionExecutor>>,
+    functions: Rc<HashMap<String, FunctionSet>>,
 }
 
 impl Template {
     pub (crate) fn new(
         syntax: Syntax,
-        functions: HashMap<String, Box<dyn FunctionExecutor>>,
+        functions: Rc<HashMap<String, FunctionSet>>,
     ) -> Template {
         return Template {
             syntax,
@@ -45,66 +61,484 @@ impl Template {
         return builder.build(format);
     }
 
+    /// Creates template using passed RFC 6570 URI template format.
+    pub fn parse_uri_template(format: &str) -> Result<Template, TemplateBuildError> {
+        let builder = TemplateBuilder::new();
+        return builder.build_uri_template(format);
+    }
+
     /// Templates the passed structure.
     pub fn serialize<T>(&self, value: &T) -> Result<String, TemplateError> 
         where 
             T: Serialize,
     {
+        return self.serialize_with_context(value, &TemplateContext::new());
+    }
+
+    /// Templates the passed structure with context variables available to function executors.
+    /// See `FunctionContext::get_variable`.
+    pub fn serialize_with_context<T>(&self, value: &T, context: &TemplateContext) -> Result<String, TemplateError>
+        where
+            T: Serialize,
+    {
+        let batch_state = RefCell::new(FunctionState::new());
+        return self.serialize_with_state(value, &RenderState::new(context, &batch_state));
+    }
+
+    /// Templates the passed structures sharing batch state of function executors: `seq:counter()` numbers items of batch.
+    ///
+    /// Every call starts new batch. See `FunctionContext::get_batch_state`.
+    pub fn render_batch<I>(&self, values: I) -> Result<Vec<String>, TemplateError>
+        where
+            I: IntoIterator,
+            I::Item: Serialize,
+    {
+        return self.render_batch_with_context(values, &TemplateContext::new());
+    }
+
+    /// Templates the passed structures sharing batch state with context variables available to function executors.
+    pub fn render_batch_with_context<I>(&self, values: I, context: &TemplateContext) -> Result<Vec<String>, TemplateError>
+        where
+            I: IntoIterator,
+            I::Item: Serialize,
+    {
+        let batch_state = RefCell::new(FunctionState::new());
+        let mut result = Vec::new();
+        for value in values {
+            result.push(self.serialize_with_state(&value, &RenderState::new(context, &batch_state))?);
+        }
+        return Ok(result);
+    }
+
+    fn serialize_with_state<T>(&self, value: &T, state: &RenderState) -> Result<String, TemplateError>
+        where
+            T: Serialize,
+    {
+        let mut result = String::new();
+        let document = create_document(value)?;
+        let mut placeholder_index = 0;
+        for token in self.syntax.iter_tokens() {
+            match token {
+                Token::Text(ref text) => {
+                    result += text;
+                },
+                Token::Template(ref template) => {
+                    result += self.read_field(&document, state, placeholder_index, template.get_field_read_token())?.as_str();
+                    placeholder_index += 1;
+                },
+                Token::UriExpression(ref expression) => {
+                    result += expand_uri_expression(&document, expression)?.as_str();
+                },
+                Token::Include(ref include) => {
+                    let template_name = include.get_template_name().as_string_ref();
+                    return Err(TemplateError::TemplateNotFound(template_name.to_string()));
+                },
+            }
+        }
+        return Ok(result);
+    }
+
+    /// Templates the passed field values without serialization.
+    ///
+    /// Used by `#[derive(OnelineTemplate)]` (feature `macros`). URI template expressions are not supported.
+    pub fn render<T>(&self, values: &T) -> Result<String, TemplateError>
+        where
+            T: FieldValues + ?Sized,
+    {
+        return self.render_with_context(values, &TemplateContext::new());
+    }
+
+    /// Templates the passed field values without serialization with context variables available to function executors.
+    pub fn render_with_context<T>(&self, values: &T, context: &TemplateContext) -> Result<String, TemplateError>
+        where
+            T: FieldValues + ?Sized,
+    {
+        let batch_state = RefCell::new(FunctionState::new());
+        let state = RenderState::new(context, &batch_state);
         let mut result = String::new();
-        let document: Document = Document::new(value).map_err(|_| {
-            // Anyway there is no information inside unstructured::UnstructuredError.
-            return TemplateError::SerializationError;
-        })?;
+        let source = FieldValuesSource::new(values);
+        let mut placeholder_index = 0;
         for token in self.syntax.iter_tokens() {
             match token {
                 Token::Text(ref text) => {
                     result += text;
                 },
                 Token::Template(ref template) => {
-                    result += self.read_field(&document, template.get_field_read_token())?.as_str();
+                    result += self.read_field(&source, &state, placeholder_index, template.get_field_read_token())?.as_str();
+                    placeholder_index += 1;
+                },
+                Token::UriExpression(..) => {
+                    return Err(TemplateError::UriExpressionNotSupported);
+                },
+                Token::Include(ref include) => {
+                    let template_name = include.get_template_name().as_string_ref();
+                    return Err(TemplateError::TemplateNotFound(template_name.to_string()));
                 },
             }
         }
         return Ok(result);
     }
 
-    fn read_field(&self, mut document: &Document, template: &FieldReadToken) -> Result<String, TemplateError> {
-        for field in template.get_path().get_fields().iter() {
-            match field {
-                Field::Field(field_name) => {
-                    document = &document[field_name.as_str()];
+    /// Evaluates placeholders whose paths are available in `value` and returns narrower template.
+    ///
+    /// Evaluated placeholders are folded into text, other placeholders are kept as is.
+    /// Path is available when it points to any value except missing field, unit or empty option.
+    /// Placeholder with field arguments is evaluated only when paths of its field arguments are available too.
+    /// Placeholder calling stateful function, e.g. `seq:counter`, is never evaluated, see `FunctionSchema::with_state`.
+    /// Placeholder of URI template is evaluated only when all of its variables are available.
+    pub fn bind<T>(&self, value: &T) -> Result<Template, TemplateError>
+        where
+            T: Serialize,
+    {
+        let document = create_document(value)?;
+        let context = TemplateContext::new();
+        let batch_state = RefCell::new(FunctionState::new());
+        let state = RenderState::new(&context, &batch_state);
+        let mut tokens = Vec::new();
+        let mut placeholder_index = 0;
+        for token in self.syntax.iter_tokens() {
+            match token {
+                Token::Text(ref text) => {
+                    tokens.push(Token::Text(text.clone()));
+                },
+                Token::Template(ref template) => {
+                    let template = template.get_field_read_token();
+                    let mut paths = vec![template.get_path()];
+                    collect_argument_paths(template.get_function_calls(), &mut paths);
+                    let is_available = !self.uses_state(template.get_function_calls()) && paths
+                        .iter()
+                        .all(|path| {
+                            return is_document_bound(select_document(&document, path));
+                        });
+                    if is_available {
+                        tokens.push(Token::Text(self.read_field(&document, &state, placeholder_index, template)?));
+                    } else {
+                        tokens.push(token.clone());
+                    }
+                    placeholder_index += 1;
+                },
+                Token::UriExpression(ref expression) => {
+                    let is_available = expression
+                        .get_variables()
+                        .iter()
+                        .all(|variable| {
+                            return is_document_bound(select_document(&document, variable.get_path()));
+                        });
+                    if is_available {
+                        tokens.push(Token::Text(expand_uri_expression(&document, expression)?));
+                    } else {
+                        tokens.push(token.clone());
+                    }
+                },
+                Token::Include(..) => {
+                    tokens.push(token.clone());
+                },
+            }
+        }
+        let syntax = Syntax::from_tokens(tokens);
+        return Ok(Template::new(syntax, self.functions.clone()));
+    }
+
+    /// Checks whether function calls or functions passed as their arguments are stateful.
+    fn uses_state(&self, function_calls: &[FunctionCallToken]) -> bool {
+        return function_calls
+            .iter()
+            .any(|function_call| {
+                let function_name = function_call.get_function_name().as_string_ref();
+                let uses_state = self
+                    .functions
+                    .get(function_name)
+                    .map(FunctionSet::uses_state)
+                    .unwrap_or(false);
+                return uses_state || function_call
+                    .get_arguments()
+                    .iter()
+                    .any(|argument| {
+                        return match argument {
+                            FunctionCallArgumentToken::Function(ref function_call) => self.uses_state(std::slice::from_ref(function_call)),
+                            _ => false,
+                        };
+                    });
+            });
+    }
+
+    /// Returns the longest static prefix of produced strings.
+    ///
+    /// Prefix is built from leading text of template: `reports/2024/{self.id}.json` gives `reports/2024/`.
+    /// Text of URI template is returned percent-encoded, the same way as it is written into produced strings.
+    pub fn static_prefix(&self) -> String {
+        let mut result = String::new();
+        for token in self.syntax.iter_tokens() {
+            match token {
+                Token::Text(ref text) => {
+                    result += text;
+                },
+                Token::Template(..) | Token::UriExpression(..) | Token::Include(..) => {
+                    break;
+                },
+            }
+        }
+        return result;
+    }
+
+    /// Returns `/`-separated segments of static prefix that are not affected by placeholders.
+    ///
+    /// `reports/2024/{self.id}.json` and `reports/2024/report_{self.id}.json` give `["reports", "2024"]`.
+    /// Template without placeholders gives all of its segments. Empty segments are skipped:
+    /// `/reports//{self.id}` gives `["reports"]`, `{self.tenant}/reports` gives no segments.
+    pub fn static_segments(&self) -> Vec<String> {
+        let static_prefix = self.static_prefix();
+        let is_static = self
+            .syntax
+            .iter_tokens()
+            .all(|token| {
+                return matches!(token, Token::Text(..));
+            });
+        let static_prefix = if is_static {
+            static_prefix.as_str()
+        } else {
+            match static_prefix.rfind('/') {
+                Some(index) => &static_prefix[..index],
+                None => {
+                    return Vec::new();
+                },
+            }
+        };
+        return static_prefix
+            .split('/')
+            .filter(|segment| {
+                return !segment.is_empty();
+            })
+            .map(str::to_string)
+            .collect();
+    }
+
+    /// Returns static prefix of template bound with partial value. See `Template::bind` and `Template::static_prefix`.
+    pub fn static_prefix_with<T>(&self, value: &T) -> Result<String, TemplateError>
+        where
+            T: Serialize,
+    {
+        let template = self.bind(value)?;
+        return Ok(template.static_prefix());
+    }
+
+    /// Returns glob pattern that matches every string produced by template.
+    ///
+    /// Every placeholder is replaced with `*`: `directory/file_no_{self.file_id}.json` gives `directory/file_no_*.json`.
+    pub fn to_glob(&self) -> String {
+        let mut result = String::new();
+        for token in self.syntax.iter_tokens() {
+            match token {
+                Token::Text(ref text) => {
+                    result += &escape_glob(text);
+                },
+                Token::Template(..) | Token::UriExpression(..) | Token::Include(..) => {
+                    if !result.ends_with('*') || result.ends_with("\\*") {
+                        result.push('*');
+                    }
+                },
+            }
+        }
+        return result;
+    }
+
+    /// Returns regex pattern that matches every string produced by template.
+    ///
+    /// Every placeholder is replaced with named capture group. Group name is built from field path:
+    /// `{self.file_id}` gives `(?P<file_id>...)`.
+    /// Pattern inside group is narrowed using output shape of the last function in pipeline:
+    /// `{self.file_id | uint:hex_fmt(8uint)}` gives `(?P<file_id>[0-9a-f]{8,})`.
+    pub fn to_regex(&self) -> String {
+        let mut names = CaptureGroupNames::new();
+        let mut result = String::from("^");
+        for token in self.syntax.iter_tokens() {
+            match token {
+                Token::Text(ref text) => {
+                    result += &escape_regex(text);
+                },
+                Token::Template(ref template) => {
+                    let template = template.get_field_read_token();
+                    let name = names.next_name(std::iter::once(template.get_path()));
+                    let pattern = self.field_read_pattern(template);
+                    result += &format!("(?P<{}>{})", name, pattern);
+                },
+                Token::UriExpression(ref expression) => {
+                    let paths = expression
+                        .get_variables()
+                        .iter()
+                        .map(|variable| {
+                            return variable.get_path();
+                        });
+                    let name = names.next_name(paths);
+                    result += &format!("(?P<{}>.*?)", name);
                 },
-                Field::Index(index) => {
-                    document = &document[index];
+                Token::Include(..) => {
+                    result += "(?:.*?)";
                 },
             }
         }
-        let mut value = convert_document(document, template.get_path())?;
+        result += "$";
+        return result;
+    }
+
+    fn field_read_pattern(&self, template: &FieldReadToken) -> String {
+        let function_call = match template.get_function_calls().last() {
+            Some(function_call) => function_call,
+            None => {
+                return ".*?".to_string();
+            },
+        };
+        let function_name = function_call.get_function_name().as_string_ref();
+        let function_set = match self.functions.get(function_name) {
+            Some(function_set) => function_set,
+            None => {
+                return ".*?".to_string();
+            },
+        };
+        let arguments: Vec<_> = function_call
+            .get_arguments()
+            .iter()
+            .map(convert_function_argument)
+            .collect();
+        let argument_types: Vec<_> = function_call
+            .get_arguments()
+            .iter()
+            .map(function_argument_type)
+            .collect();
+        let schema = match function_set.resolve_arguments(&argument_types).as_slice() {
+            [function_executor] => function_executor.schema(),
+            _ => {
+                return ".*?".to_string();
+            },
+        };
+        let output_shape = match schema.get_output_shape() {
+            Some(output_shape) => output_shape,
+            None => {
+                return ".*?".to_string();
+            },
+        };
+        let character_class = output_shape.get_character_class();
+        let pattern = match output_shape.get_min_length(&arguments) {
+            0 => format!("{}*", character_class),
+            1 => format!("{}+", character_class),
+            min_length => format!("{}{{{},}}", character_class, min_length),
+        };
+        return pattern;
+    }
+
+    fn read_field(
+        &self,
+        source: &dyn FieldSource,
+        state: &RenderState,
+        placeholder_index: usize,
+        template: &FieldReadToken,
+    ) -> Result<String, TemplateError> {
+        let path = format!("{}", template.get_path());
+        let mut value = source
+            .read(template.get_path())?
+            .ok_or_else(|| {
+                return TemplateError::PathNotFound(path.clone());
+            })?;
         for function_call in template.get_function_calls().iter() {
-            value = self.execute_function(value, function_call)?;
+            value = self.execute_function(source, state, placeholder_index, &path, value, function_call)?;
         }
         let value = convert_value_to_string(value)?;
         return Ok(value);
     }
 
-    fn execute_function(&self, input: Value, function_call: &FunctionCallToken) -> Result<Value, TemplateError> {
+    fn execute_function(
+        &self,
+        source: &dyn FieldSource,
+        state: &RenderState,
+        placeholder_index: usize,
+        path: &str,
+        input: Value,
+        function_call: &FunctionCallToken,
+    ) -> Result<Value, TemplateError> {
         let function_name = function_call.get_function_name().as_string_ref();
-        let function_executor = self
+        let function_set = self
             .functions
             .get(function_name)
             .ok_or_else(|| {
                 return TemplateError::FunctionNotFound(function_name.to_string());
             })?;
-        let arguments: Vec<_> = function_call
+        let argument_types: Vec<_> = function_call
             .get_arguments()
             .iter()
-            .map(convert_function_argument)
+            .map(function_argument_type)
             .collect();
-        let value = function_executor.call(input, &arguments)?;
+        let mut arguments = Vec::new();
+        for argument in function_call.get_arguments().iter() {
+            arguments.push(self.evaluate_argument(source, argument)?);
+        }
+        let argument_caller = |argument_index: usize, input: Value| -> Result<Value, FunctionError> {
+            let function_call = match function_call.get_arguments().get(argument_index) {
+                Some(FunctionCallArgumentToken::Function(ref function_call)) => function_call,
+                _ => {
+                    return Err(FunctionError::msg(format!("Argument with index `{}` is not a function", argument_index)));
+                },
+            };
+            let value = self.execute_function(source, state, placeholder_index, path, input, function_call)?;
+            return Ok(value);
+        };
+        let field_reader = |fields: &[&str]| -> Result<Option<Value>, FunctionError> {
+            let value = source.read(&FieldPath::from_fields(fields))?;
+            return Ok(value);
+        };
+        let function_context = FunctionContext::new(
+            &argument_caller,
+            &field_reader,
+            state.get_context().get_variables(),
+            path.to_string(),
+            placeholder_index,
+            state.get_render_state(),
+            state.get_batch_state(),
+        );
+        let value = function_set.call(function_name, input, &argument_types, &arguments, &function_context)?;
+        return Ok(value);
+    }
+
+    fn evaluate_argument(&self, source: &dyn FieldSource, argument: &FunctionCallArgumentToken) -> Result<Value, TemplateError> {
+        let path = match argument {
+            FunctionCallArgumentToken::Field(ref path) => path,
+            _ => {
+                return Ok(convert_function_argument(argument));
+            },
+        };
+        let value = source
+            .read(path)?
+            .unwrap_or(Value::Option(None));
         return Ok(value);
     }
 }
 
+fn create_document<T>(value: &T) -> Result<Document, TemplateError>
+    where
+        T: Serialize,
+{
+    let document: Document = Document::new(value).map_err(|_| {
+        // Anyway there is no information inside unstructured::UnstructuredError.
+        return TemplateError::SerializationError;
+    })?;
+    return Ok(document);
+}
+
+fn collect_argument_paths<'a>(function_calls: &'a [FunctionCallToken], paths: &mut Vec<&'a FieldPath>) {
+    for function_call in function_calls.iter() {
+        for argument in function_call.get_arguments().iter() {
+            match argument {
+                FunctionCallArgumentToken::Field(ref path) => {
+                    paths.push(path);
+                },
+                FunctionCallArgumentToken::Function(ref function_call) => {
+                    collect_argument_paths(std::slice::from_ref(function_call), paths);
+                },
+                _ => {},
+            }
+        }
+    }
+}
+
 impl fmt::Debug for Template {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut functions = HashMap::new();
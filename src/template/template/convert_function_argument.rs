@@ -1,19 +1,38 @@
 use crate::template::syntax::function_call_argument_token::FunctionCallArgumentToken;
-use crate::function_executor::Value;
+use crate::function_executor::{Value, FunctionArgumentType};
 
+/// Converts literal argument into value. Field and function arguments are converted into their text.
 pub fn convert_function_argument(argument: &FunctionCallArgumentToken) -> Value {
-    match argument {
-        &FunctionCallArgumentToken::String(ref value) => {
+    match *argument {
+        FunctionCallArgumentToken::String(ref value) => {
             Value::String(value.clone())
         },
-        &FunctionCallArgumentToken::Bool(value) => {
+        FunctionCallArgumentToken::Bool(value) => {
             Value::Bool(value)
         },
-        &FunctionCallArgumentToken::UInt(value) => {
+        FunctionCallArgumentToken::UInt(value) => {
             Value::UInt(value)
         },
-        &FunctionCallArgumentToken::Int(value) => {
+        FunctionCallArgumentToken::Int(value) => {
             Value::Int(value)
         },
+        FunctionCallArgumentToken::Float(value) => {
+            Value::Float(value)
+        },
+        FunctionCallArgumentToken::Field(..) | FunctionCallArgumentToken::Function(..) => {
+            Value::String(argument.to_string())
+        },
+    }
+}
+
+pub fn function_argument_type(argument: &FunctionCallArgumentToken) -> FunctionArgumentType {
+    match *argument {
+        FunctionCallArgumentToken::String(..) => FunctionArgumentType::String,
+        FunctionCallArgumentToken::Bool(..) => FunctionArgumentType::Bool,
+        FunctionCallArgumentToken::UInt(..) => FunctionArgumentType::UInt,
+        FunctionCallArgumentToken::Int(..) => FunctionArgumentType::Int,
+        FunctionCallArgumentToken::Float(..) => FunctionArgumentType::Float,
+        FunctionCallArgumentToken::Field(..) => FunctionArgumentType::Field,
+        FunctionCallArgumentToken::Function(..) => FunctionArgumentType::Function,
     }
 }
\ No newline at end of file
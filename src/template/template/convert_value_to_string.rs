@@ -10,11 +10,10 @@ pub fn convert_value_to_string(value: Value) -> Result<String, TemplateError> {
             return Err(TemplateError::UnableConvertBoolToString);
         },
         Value::Bytes(value) => {
-            let value = String::from_utf8(value)
+            String::from_utf8(value)
                 .map_err(|error| {
                     return TemplateError::VecToUtf8ConvertationError(error);
-                })?;
-            value
+                })?
         },
         Value::Char(value) => {
             value.to_string()
@@ -31,6 +30,9 @@ pub fn convert_value_to_string(value: Value) -> Result<String, TemplateError> {
         Value::Float(..) => {
             return Err(TemplateError::UnableConvertFloatToString);
         },
+        Value::Seq(..) => {
+            return Err(TemplateError::UnableConvertSeqToString);
+        },
     };
     return Ok(value);
 }
\ No newline at end of file
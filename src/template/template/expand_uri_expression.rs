@@ -0,0 +1,219 @@
+use crate::template::syntax::uri_expression_token::UriExpressionToken;
+use crate::template::syntax::uri_variable::UriVariable;
+use crate::template::syntax::uri_modifier::UriModifier;
+use crate::template::syntax::uri_operator::UriOperator;
+use crate::template::syntax::uri_encoding::uri_encode;
+use crate::template::syntax::field_path::FieldPath;
+use crate::template::template_error::TemplateError;
+use super::select_document::select_document;
+use unstructured::{Document, Unstructured};
+
+enum UriValue {
+    Undefined,
+    String(String),
+    List(Vec<String>),
+    Map(Vec<(String, String)>),
+}
+
+fn convert_scalar(document: &Document, path: &FieldPath) -> Result<Option<String>, TemplateError> {
+    let value = match *document {
+        Unstructured::Unassigned => {
+            return Ok(None);
+        },
+        Unstructured::Null => {
+            return Ok(None);
+        },
+        Unstructured::Bool(value) => {
+            value.to_string()
+        },
+        Unstructured::Number(ref value) => {
+            value.to_string()
+        },
+        Unstructured::String(ref value) => {
+            value.clone()
+        },
+        Unstructured::Char(value) => {
+            value.to_string()
+        },
+        Unstructured::Bytes(ref value) => {
+            String::from_utf8(value.clone())
+                .map_err(|error| {
+                    return TemplateError::VecToUtf8ConvertationError(error);
+                })?
+        },
+        Unstructured::Seq(..) => {
+            return Err(TemplateError::PathContainsSeq(format!("{}", path)));
+        },
+        Unstructured::Map(..) => {
+            return Err(TemplateError::PathContainsMap(format!("{}", path)));
+        },
+        Unstructured::Option(ref value) => {
+            match value {
+                Some(value) => {
+                    return convert_scalar(value.as_ref(), path);
+                },
+                None => {
+                    return Ok(None);
+                },
+            }
+        },
+        Unstructured::Newtype(ref value) => {
+            return convert_scalar(value.as_ref(), path);
+        },
+        Unstructured::Err(..) => {
+            return Err(TemplateError::PathContainsUnknownErrorType(format!("{}", path)));
+        },
+        Unstructured::Other(..) => {
+            return Err(TemplateError::PathContainsUnknownType(format!("{}", path)));
+        },
+    };
+    return Ok(Some(value));
+}
+
+fn convert_value(document: &Document, path: &FieldPath) -> Result<UriValue, TemplateError> {
+    match *document {
+        Unstructured::Seq(ref items) => {
+            let mut list = Vec::new();
+            for item in items.iter() {
+                if let Some(item) = convert_scalar(item, path)? {
+                    list.push(item);
+                }
+            }
+            if list.is_empty() {
+                return Ok(UriValue::Undefined);
+            }
+            return Ok(UriValue::List(list));
+        },
+        Unstructured::Map(ref items) => {
+            let mut map = Vec::new();
+            for (key, value) in items.iter() {
+                let key = convert_scalar(key, path)?;
+                let value = convert_scalar(value, path)?;
+                if let (Some(key), Some(value)) = (key, value) {
+                    map.push((key, value));
+                }
+            }
+            if map.is_empty() {
+                return Ok(UriValue::Undefined);
+            }
+            return Ok(UriValue::Map(map));
+        },
+        Unstructured::Option(Some(ref value)) => {
+            return convert_value(value.as_ref(), path);
+        },
+        Unstructured::Newtype(ref value) => {
+            return convert_value(value.as_ref(), path);
+        },
+        _ => {
+            let value = match convert_scalar(document, path)? {
+                Some(value) => UriValue::String(value),
+                None => UriValue::Undefined,
+            };
+            return Ok(value);
+        },
+    }
+}
+
+fn expand_variable(
+    operator: UriOperator,
+    variable: &UriVariable,
+    value: UriValue,
+) -> Result<String, TemplateError> {
+    if let UriModifier::Prefix(..) = variable.get_modifier() {
+        if let UriValue::List(..) | UriValue::Map(..) = value {
+            return Err(TemplateError::UriPrefixNotApplicable(format!("{}", variable.get_path())));
+        }
+    }
+    let allow_reserved = operator.is_reserved_allowed();
+    let name = variable.get_name();
+    let mut result = String::new();
+    match value {
+        UriValue::Undefined => {},
+        UriValue::String(value) => {
+            let value = match variable.get_modifier() {
+                UriModifier::Prefix(length) => {
+                    value.chars().take(length).collect()
+                },
+                _ => value,
+            };
+            if operator.is_named() {
+                result += name;
+                if value.is_empty() {
+                    result += operator.get_if_empty();
+                    return Ok(result);
+                }
+                result += "=";
+            }
+            result += &uri_encode(&value, allow_reserved);
+        },
+        UriValue::List(items) => {
+            let items = items.iter().map(|item| {
+                return uri_encode(item, allow_reserved);
+            });
+            if variable.get_modifier() == UriModifier::Explode {
+                let items: Vec<_> = items.map(|item| {
+                    if !operator.is_named() {
+                        return item;
+                    }
+                    if item.is_empty() {
+                        return format!("{}{}", name, operator.get_if_empty());
+                    }
+                    return format!("{}={}", name, item);
+                }).collect();
+                result += &items.join(operator.get_separator());
+            } else {
+                if operator.is_named() {
+                    result += name;
+                    result += "=";
+                }
+                let items: Vec<_> = items.collect();
+                result += &items.join(",");
+            }
+        },
+        UriValue::Map(items) => {
+            let items = items.iter().map(|(key, value)| {
+                return (uri_encode(key, allow_reserved), uri_encode(value, allow_reserved));
+            });
+            if variable.get_modifier() == UriModifier::Explode {
+                let items: Vec<_> = items.map(|(key, value)| {
+                    if operator.is_named() && value.is_empty() {
+                        return format!("{}{}", key, operator.get_if_empty());
+                    }
+                    return format!("{}={}", key, value);
+                }).collect();
+                result += &items.join(operator.get_separator());
+            } else {
+                if operator.is_named() {
+                    result += name;
+                    result += "=";
+                }
+                let items: Vec<_> = items.map(|(key, value)| {
+                    return format!("{},{}", key, value);
+                }).collect();
+                result += &items.join(",");
+            }
+        },
+    }
+    return Ok(result);
+}
+
+pub fn expand_uri_expression(document: &Document, expression: &UriExpressionToken) -> Result<String, TemplateError> {
+    let operator = expression.get_operator();
+    let mut result = String::new();
+    let mut is_first = true;
+    for variable in expression.get_variables().iter() {
+        let value = select_document(document, variable.get_path());
+        let value = convert_value(value, variable.get_path())?;
+        if let UriValue::Undefined = value {
+            continue;
+        }
+        if is_first {
+            result += operator.get_first();
+        } else {
+            result += operator.get_separator();
+        }
+        result += &expand_variable(operator, variable, value)?;
+        is_first = false;
+    }
+    return Ok(result);
+}
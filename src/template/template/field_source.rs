@@ -0,0 +1,70 @@
+use crate::function_executor::Value;
+use crate::template::syntax::field_path::FieldPath;
+use crate::template::syntax::field::Field;
+use crate::template::field_values::FieldValues;
+use crate::template::template_error::TemplateError;
+use super::convert_document::convert_document;
+use super::select_document::select_document;
+use unstructured::{Document, Unstructured};
+
+/// Source of values of fields read by template.
+pub trait FieldSource {
+    /// Returns value of field or `None` when field is missing or unit.
+    fn read(&self, path: &FieldPath) -> Result<Option<Value>, TemplateError>;
+}
+
+impl FieldSource for Document {
+    fn read(&self, path: &FieldPath) -> Result<Option<Value>, TemplateError> {
+        let document = select_document(self, path);
+        if !is_document_available(document) {
+            return Ok(None);
+        }
+        let value = convert_document(document, path)?;
+        return Ok(Some(value));
+    }
+}
+
+/// Reads fields from `FieldValues`.
+pub struct FieldValuesSource<'a, T: ?Sized> {
+    values: &'a T,
+}
+
+impl <'a, T: ?Sized>FieldValuesSource<'a, T> {
+    pub fn new(values: &'a T) -> FieldValuesSource<'a, T> {
+        return FieldValuesSource {
+            values,
+        }
+    }
+}
+
+impl <'a, T>FieldSource for FieldValuesSource<'a, T>
+    where
+        T: FieldValues + ?Sized,
+{
+    fn read(&self, path: &FieldPath) -> Result<Option<Value>, TemplateError> {
+        let fields: Vec<String> = path
+            .get_fields()
+            .iter()
+            .map(|field| {
+                return match field {
+                    Field::Field(ref field_name) => field_name.as_str().to_string(),
+                    Field::Index(index) => index.to_string(),
+                };
+            })
+            .collect();
+        let fields: Vec<&str> = fields
+            .iter()
+            .map(String::as_str)
+            .collect();
+        return Ok(self.values.field_value(&fields));
+    }
+}
+
+pub fn is_document_available(document: &Document) -> bool {
+    return !matches!(document, Unstructured::Unassigned | Unstructured::Null);
+}
+
+/// Checks whether field is bound by `Template::bind`. Empty option is not bound, it may be filled later.
+pub fn is_document_bound(document: &Document) -> bool {
+    return is_document_available(document) && !matches!(document, Unstructured::Option(None));
+}
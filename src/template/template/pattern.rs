@@ -0,0 +1,70 @@
+use crate::template::syntax::field_path::FieldPath;
+use crate::template::syntax::field::Field;
+use std::collections::HashMap;
+
+const GLOB_SPECIAL_CHARS: &str = "\\*?[]{}";
+const REGEX_SPECIAL_CHARS: &str = "\\.+*?()|[]{}^$#&-~";
+
+pub fn escape_glob(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        if GLOB_SPECIAL_CHARS.contains(c) {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    return result;
+}
+
+pub fn escape_regex(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        if REGEX_SPECIAL_CHARS.contains(c) {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    return result;
+}
+
+/// Generates unique names of regex capture groups.
+pub struct CaptureGroupNames {
+    used_names: HashMap<String, usize>,
+}
+
+impl CaptureGroupNames {
+    pub fn new() -> CaptureGroupNames {
+        return CaptureGroupNames {
+            used_names: HashMap::new(),
+        }
+    }
+
+    pub fn next_name<'a>(&mut self, paths: impl Iterator<Item=&'a FieldPath>) -> String {
+        let mut fields = Vec::new();
+        for path in paths {
+            for field in path.get_fields().iter() {
+                match field {
+                    Field::Field(field_name) => {
+                        fields.push(field_name.as_str().to_string());
+                    },
+                    Field::Index(index) => {
+                        fields.push(index.to_string());
+                    },
+                }
+            }
+        }
+        let mut name = fields.join("_");
+        if name.is_empty() {
+            name = "self".to_string();
+        }
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            name = format!("_{}", name);
+        }
+        let counter = self.used_names.entry(name.clone()).or_insert(0);
+        *counter += 1;
+        if *counter > 1 {
+            name = format!("{}_{}", name, counter);
+        }
+        return name;
+    }
+}
@@ -0,0 +1,32 @@
+use crate::function_executor::FunctionState;
+use crate::template::template_context::TemplateContext;
+use std::cell::RefCell;
+
+/// Context variables and states passed into function executors while one value is templated.
+pub struct RenderState<'a> {
+    context: &'a TemplateContext,
+    render_state: RefCell<FunctionState>,
+    batch_state: &'a RefCell<FunctionState>,
+}
+
+impl <'a>RenderState<'a> {
+    pub fn new(context: &'a TemplateContext, batch_state: &'a RefCell<FunctionState>) -> RenderState<'a> {
+        return RenderState {
+            context,
+            render_state: RefCell::new(FunctionState::new()),
+            batch_state,
+        }
+    }
+
+    pub fn get_context(&self) -> &TemplateContext {
+        return self.context;
+    }
+
+    pub fn get_render_state(&self) -> &RefCell<FunctionState> {
+        return &self.render_state;
+    }
+
+    pub fn get_batch_state(&self) -> &RefCell<FunctionState> {
+        return self.batch_state;
+    }
+}
@@ -0,0 +1,17 @@
+use crate::template::syntax::field_path::FieldPath;
+use crate::template::syntax::field::Field;
+use unstructured::Document;
+
+pub fn select_document<'a>(mut document: &'a Document, path: &FieldPath) -> &'a Document {
+    for field in path.get_fields().iter() {
+        match field {
+            Field::Field(field_name) => {
+                document = &document[field_name.as_str()];
+            },
+            Field::Index(index) => {
+                document = &document[index];
+            },
+        }
+    }
+    return document;
+}
@@ -1,4 +1,5 @@
 use crate::function_executor::FunctionError;
+use crate::template::build_error::BuildError;
 use std::error::Error;
 use std::fmt;
 use std::string::FromUtf8Error;
@@ -18,6 +19,8 @@ pub enum TemplateError {
     PathContainsUnknownErrorType(String),
     /// Function not found.
     FunctionNotFound(String),
+    /// Function has no overload for type of input value. Contains function name and input type.
+    OverloadNotFound(String, String),
     /// Function execution error.
     FunctionError(FunctionError),
     /// Structure serialization error.
@@ -28,8 +31,18 @@ pub enum TemplateError {
     UnableConvertOptionToString,
     /// Unable convert float to string.
     UnableConvertFloatToString,
+    /// Unable convert sequence to string.
+    UnableConvertSeqToString,
     /// Unable convert `vec<u8>` to utf-8 string.
     VecToUtf8ConvertationError(FromUtf8Error),
+    /// URI template prefix modifier is applied to list or map.
+    UriPrefixNotApplicable(String),
+    /// Template with passed name not found.
+    TemplateNotFound(String),
+    /// URI template expression is formatted without serialization.
+    UriExpressionNotSupported,
+    /// Template built on first use can not be built, e.g. when it calls unknown function.
+    BuildError(BuildError),
 }
 
 impl From<FunctionError> for TemplateError {
@@ -38,6 +51,12 @@ impl From<FunctionError> for TemplateError {
     }
 }
 
+impl From<BuildError> for TemplateError {
+    fn from(error: BuildError) -> Self {
+        return TemplateError::BuildError(error);
+    }
+}
+
 impl fmt::Display for TemplateError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -59,6 +78,9 @@ impl fmt::Display for TemplateError {
             TemplateError::FunctionNotFound(ref function_name) => {
                 write!(f, "Function `{}` not found", function_name)
             },
+            TemplateError::OverloadNotFound(ref function_name, ref input_type) => {
+                write!(f, "Function `{}` has no overload for input type `{}`", function_name, input_type)
+            },
             TemplateError::FunctionError(ref error) => {
                 write!(f, "{}", error)
             },
@@ -74,9 +96,24 @@ impl fmt::Display for TemplateError {
             TemplateError::UnableConvertFloatToString => {
                 write!(f, "Convertation float to string is not supported")
             },
+            TemplateError::UnableConvertSeqToString => {
+                write!(f, "Convertation seq to string is not supported")
+            },
             TemplateError::VecToUtf8ConvertationError(ref error) => {
                 write!(f, "Error while converting vec to string: {}", error)
             },
+            TemplateError::UriPrefixNotApplicable(ref path) => {
+                write!(f, "Prefix modifier is not applicable to composite value at path `{}`", path)
+            },
+            TemplateError::TemplateNotFound(ref template_name) => {
+                write!(f, "Template `{}` not found", template_name)
+            },
+            TemplateError::UriExpressionNotSupported => {
+                write!(f, "URI template expressions are supported only by `Template::serialize`")
+            },
+            TemplateError::BuildError(ref error) => {
+                write!(f, "{}", error)
+            },
         }
     }
 }
@@ -102,6 +139,9 @@ impl Error for TemplateError {
             TemplateError::FunctionNotFound(..) => {
                 return None;
             },
+            TemplateError::OverloadNotFound(..) => {
+                return None;
+            },
             TemplateError::FunctionError(..) => {
                 return None;
             },
@@ -117,9 +157,24 @@ impl Error for TemplateError {
             TemplateError::UnableConvertFloatToString => {
                 return None;
             },
+            TemplateError::UnableConvertSeqToString => {
+                return None;
+            },
             TemplateError::VecToUtf8ConvertationError(ref error) => {
                 return Some(error);
             },
+            TemplateError::UriPrefixNotApplicable(..) => {
+                return None;
+            },
+            TemplateError::TemplateNotFound(..) => {
+                return None;
+            },
+            TemplateError::UriExpressionNotSupported => {
+                return None;
+            },
+            TemplateError::BuildError(ref error) => {
+                return Some(error);
+            },
         }
     }
 }
\ No newline at end of file
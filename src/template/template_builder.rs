@@ -1,114 +1,354 @@
 use crate::template::template_build_error::TemplateBuildError;
-use crate::template::template::Template;
+use crate::template::template::{Template, convert_function_argument, function_argument_type};
 use crate::template::argument_types_differ_error::ArgumentTypesDifferError;
-use crate::function_executor::FunctionExecutor;
+use crate::function_executor::{FunctionExecutor, FunctionModule, FunctionCatalogue, FnExecutor, IntoFunction, FunctionArgumentType, Renamed, ValueType};
+use crate::template::syntax::function_call_argument_token::FunctionCallArgumentToken;
 use crate::template::syntax::function_call_token::FunctionCallToken;
-use crate::function_executor::FunctionArgumentType;
 use crate::template::syntax::syntax::Syntax;
-use crate::template::syntax::function_call_argument_token::FunctionCallArgumentToken;
+use crate::template::function_set::FunctionSet;
+use crate::template::function_overload::FunctionOverload;
+use crate::template::overwrite_policy::OverwritePolicy;
 use crate::template::syntax::token::Token;
 use crate::template::syntax::template_token::TemplateToken;
 use crate::functions;
 use std::str::FromStr;
 use std::collections::HashMap;
+use std::rc::Rc;
 
 
 /// Creates template.
 pub struct TemplateBuilder {
-    functions: HashMap<String, Box<dyn FunctionExecutor>>,
+    functions: HashMap<String, FunctionSet>,
+    overwrite_policy: OverwritePolicy,
+    error: Option<TemplateBuildError>,
 }
 
 impl TemplateBuilder {
     /// Creates template builder WITH default functions.
     pub fn new() -> TemplateBuilder {
-        return TemplateBuilder::new_empty()
-            .with_function(functions::bool::ToString)
-            .with_function(functions::bool::UnwrapOr)
-            .with_function(functions::float::ToString)
-            .with_function(functions::int::Abs)
-            .with_function(functions::int::Hex)
-            .with_function(functions::int::HexFmt)
-            .with_function(functions::int::ToString)
-            .with_function(functions::string::Trim)
-            .with_function(functions::string::UnwrapOr)
-            .with_function(functions::string::TrimStart)
-            .with_function(functions::string::TrimEnd)
-            .with_function(functions::string::SubStr)
-            .with_function(functions::uint::Hex)
-            .with_function(functions::uint::HexFmt)
-            .with_function(functions::uint::ToString)
-            .with_function(functions::debug::DebugType);
+        let template_builder = TemplateBuilder::new_empty()
+            .with_module(functions::bool::module())
+            .with_module(functions::bytes::module())
+            .with_module(functions::char::module())
+            .with_module(functions::float::module())
+            .with_module(functions::int::module())
+            .with_module(functions::string::module())
+            .with_module(functions::seq::module())
+            .with_module(functions::uint::module())
+            .with_module(functions::option::module())
+            .with_module(functions::human::module())
+            .with_module(functions::debug::module())
+            .with_module(functions::generic::module());
+        #[cfg(feature = "time")]
+        let template_builder = template_builder.with_module(functions::time::module());
+        #[cfg(feature = "hash")]
+        let template_builder = template_builder.with_module(functions::hash::module());
+        #[cfg(feature = "regex")]
+        let template_builder = template_builder.with_module(functions::regex::module());
+        return template_builder;
     }
 
     /// Creates template builder WITHOUT default functions.
     pub fn new_empty() -> TemplateBuilder {
         return TemplateBuilder {
             functions: HashMap::new(),
+            overwrite_policy: OverwritePolicy::Replace,
+            error: None,
         }
     }
 
     /// Adds function executor to template builder.
-    pub fn with_function(mut self, function_executor: impl FunctionExecutor + 'static) -> Self {
-        let function_executor: Box<dyn FunctionExecutor> = Box::new(function_executor);
+    ///
+    /// Function executors with the same name and different input types or argument types are registered as overloads.
+    /// Overload is selected by arguments and type of input value: `{self.x | to_string()}` calls `bool:to_string`
+    /// for bool and `uint:to_string` for uint. Function executor with the same name, input type and argument types
+    /// is handled according to overwrite policy, see `TemplateBuilder::with_overwrite_policy`.
+    pub fn with_function(self, function_executor: impl FunctionExecutor + 'static) -> Self {
+        return self.insert_function(Rc::new(function_executor), None);
+    }
+
+    /// Adds all function executors of module: `with_module(functions::string::module())`.
+    ///
+    /// Function executors are registered under namespace named after module, see `TemplateBuilder::without_namespace`.
+    pub fn with_module(mut self, module: FunctionModule) -> Self {
+        let namespace = module.get_name().to_string();
+        for function_executor in module.into_functions() {
+            self = self.insert_function(function_executor, Some(namespace.clone()));
+        }
+        return self;
+    }
+
+    /// Removes functions of namespace: `without_namespace("debug")` removes `debug:type` and others.
+    ///
+    /// Namespace of functions added by `with_module` is module name: `without_namespace("human")` removes
+    /// `uint:bytes_human` and `without_namespace("generic")` removes `to_string` overloads.
+    /// Namespace of other functions is prefix of function name before `:`.
+    pub fn without_namespace(mut self, namespace: &str) -> Self {
+        for function_set in self.functions.values_mut() {
+            function_set.remove_namespace(namespace);
+        }
+        self.functions.retain(|_, function_set| {
+            return !function_set.is_empty();
+        });
+        return self;
+    }
+
+    /// Registers all overloads of function `function_name` under another name: `with_alias("hex", "uint:hex")`.
+    ///
+    /// Building template fails with `TemplateBuildError::FunctionNotFound` if function is not registered.
+    pub fn with_alias(mut self, alias: impl Into<String>, function_name: &str) -> Self {
+        let alias = alias.into();
+        let overloads: Vec<_> = match self.functions.get(function_name) {
+            Some(function_set) => function_set.overloads().cloned().collect(),
+            None => {
+                self.set_error(TemplateBuildError::FunctionNotFound(function_name.to_string()));
+                return self;
+            },
+        };
+        for overload in overloads {
+            self = self.insert_function(Rc::new(Renamed::new(alias.clone(), overload)), None);
+        }
+        return self;
+    }
+
+    /// Sets policy for functions registered later with the same name, input type and argument types as existing ones.
+    ///
+    /// Default policy is `OverwritePolicy::Replace`, so default functions can be overridden:
+    /// `TemplateBuilder::new().with_function(MyHex)` replaces `uint:hex`.
+    /// Use `OverwritePolicy::Error` to report name collisions when template is built.
+    pub fn with_overwrite_policy(mut self, overwrite_policy: OverwritePolicy) -> Self {
+        self.overwrite_policy = overwrite_policy;
+        return self;
+    }
+
+    fn insert_function(mut self, function_executor: Rc<dyn FunctionExecutor>, namespace: Option<String>) -> Self {
         let schema = function_executor.schema();
-        let function_name = schema.get_function_name().clone();
-        let _ = self.functions.insert(function_name, function_executor);
+        let function_name = schema.get_function_name();
+        let namespace = namespace.or_else(|| {
+            return function_name
+                .split_once(':')
+                .map(|(namespace, _)| namespace.to_string());
+        });
+        let function_set = self
+            .functions
+            .entry(function_name.clone())
+            .or_insert_with(FunctionSet::new);
+        let overload = FunctionOverload::new(function_executor, namespace);
+        if function_set.contains_signature_of(&overload) {
+            match self.overwrite_policy {
+                OverwritePolicy::Replace => {},
+                OverwritePolicy::Keep => {
+                    return self;
+                },
+                OverwritePolicy::Error => {
+                    let error = TemplateBuildError::FunctionOverwritten(function_name.clone());
+                    self.set_error(error);
+                    return self;
+                },
+            }
+        }
+        function_set.insert(overload);
         return self;
     }
 
-    fn validate_function_call(&self, function_call: &FunctionCallToken) -> Result<(), TemplateBuildError> {
+    /// Keeps the first error of function registration. It is returned when template is built.
+    fn set_error(&mut self, error: TemplateBuildError) {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+    }
+
+    /// Returns schemas of registered functions with descriptions, argument names and examples.
+    ///
+    /// `TemplateBuilder::new().functions().to_markdown()` renders reference of default functions.
+    pub fn functions(&self) -> FunctionCatalogue {
+        let schemas = self
+            .functions
+            .values()
+            .flat_map(FunctionSet::iter)
+            .map(FunctionExecutor::schema)
+            .collect();
+        return FunctionCatalogue::new(schemas);
+    }
+
+    /// Returns the first error of function registration.
+    pub (crate) fn take_error(&mut self) -> Result<(), TemplateBuildError> {
+        return match self.error.take() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        };
+    }
+
+    /// Adds rust closure or function pointer as function executor.
+    ///
+    /// First parameter of closure is input value, other parameters are arguments declared within template.
+    /// Schema of function is derived from types of closure, see `FnExecutor`.
+    ///
+    /// ```rust
+    /// use oneline_template::template::TemplateBuilder;
+    ///
+    /// let template = TemplateBuilder::new()
+    ///     .with_fn("string:repeat", |value: String, count: usize| -> String {
+    ///         return value.repeat(count);
+    ///     })
+    ///     .build("{self | string:repeat(3uint)}")
+    ///     .unwrap();
+    /// assert_eq!(template.serialize(&"ab").unwrap(), "ababab");
+    /// ```
+    pub fn with_fn<F, Args>(self, function_name: impl Into<String>, function: F) -> Self
+        where
+            F: IntoFunction<Args>,
+    {
+        return self.with_function(FnExecutor::new(function_name, function));
+    }
+
+    /// Validates function call and returns type of its result when it is known.
+    fn validate_function_call(&self, function_call: &FunctionCallToken, input_type: Option<&ValueType>) -> Result<Option<ValueType>, TemplateBuildError> {
         let function_name = function_call.get_function_name().as_string_ref();
-        let function_executor = match self.functions.get(function_name) {
-            Some(function_executor) => {function_executor},
+        let function_set = match self.functions.get(function_name) {
+            Some(function_set) => {function_set},
             None => {
                 return Err(TemplateBuildError::FunctionNotFound(function_name.to_string()));
             },
         };
-        let schema = function_executor.schema();
-        if function_call.get_arguments().len() != schema.get_arguments().len() {
-            return Err(TemplateBuildError::ArgumentsLengthDiffer(function_name.to_string()));
+        let argument_types: Vec<_> = function_call
+            .get_arguments()
+            .iter()
+            .map(function_argument_type)
+            .collect();
+        let overloads = function_set.resolve_arguments(&argument_types);
+        if overloads.is_empty() {
+            return Err(Self::arguments_error(function_name, function_set, &argument_types));
         }
-        let iterator = function_call.get_arguments().iter().zip(schema.get_arguments().iter());
-        for (argument_index, (actual_argument, expected_argument)) in iterator.enumerate() {
-            let actual_argument_type = match actual_argument {
-                FunctionCallArgumentToken::String(..) => FunctionArgumentType::String,
-                FunctionCallArgumentToken::Bool(..) => FunctionArgumentType::Bool,
-                FunctionCallArgumentToken::UInt(..) => FunctionArgumentType::UInt,
-                FunctionCallArgumentToken::Int(..) => FunctionArgumentType::Int,
-            };
-            let expected_argument_type = expected_argument.get_type();
-            if &actual_argument_type != expected_argument_type {
-                return Err(ArgumentTypesDifferError::new(function_name.to_string(), argument_index).into());
+        // Function passed as argument is applied to value inside of option.
+        let argument_input_type = match input_type {
+            Some(ValueType::Option(ref value_type)) => Some(value_type.as_ref()),
+            _ => None,
+        };
+        for argument in function_call.get_arguments().iter() {
+            if let FunctionCallArgumentToken::Function(ref function_call) = argument {
+                let _ = self.validate_function_call(function_call, argument_input_type)?;
             }
         }
-        return Ok(());
+        // Input type is checked only when it is known, otherwise it is checked when template is executed.
+        let overloads: Vec<_> = overloads
+            .into_iter()
+            .filter(|overload| {
+                return match (overload.schema().get_input_type(), input_type) {
+                    (Some(expected_type), Some(input_type)) => expected_type.is_compatible(input_type),
+                    _ => true,
+                };
+            })
+            .collect();
+        if overloads.is_empty() {
+            let input_type = input_type.cloned().unwrap_or(ValueType::Any);
+            return Err(TemplateBuildError::InputTypeNotMatch(function_name.to_string(), input_type));
+        }
+        let arguments: Vec<_> = function_call
+            .get_arguments()
+            .iter()
+            .map(convert_function_argument)
+            .collect();
+        for overload in overloads.iter() {
+            overload
+                .validate_arguments(&arguments)
+                .map_err(|error| {
+                    return TemplateBuildError::InvalidArguments(function_name.to_string(), error);
+                })?;
+        }
+        let output_type = overloads[0].schema().get_output_type().cloned();
+        let is_output_type_same = overloads
+            .iter()
+            .all(|overload| {
+                return overload.schema().get_output_type() == output_type.as_ref();
+            });
+        if !is_output_type_same {
+            return Ok(None);
+        }
+        return Ok(output_type);
+    }
+
+    /// Describes why none of overloads accepts arguments using overload with the same number of arguments.
+    fn arguments_error(function_name: &str, function_set: &FunctionSet, argument_types: &[FunctionArgumentType]) -> TemplateBuildError {
+        let schema = function_set
+            .iter()
+            .map(|overload| {
+                return overload.schema();
+            })
+            .find(|schema| {
+                return schema.get_arguments().len() == argument_types.len();
+            });
+        let schema = match schema {
+            Some(schema) => schema,
+            None => {
+                return TemplateBuildError::ArgumentsLengthDiffer(function_name.to_string());
+            },
+        };
+        let argument_index = schema
+            .get_arguments()
+            .iter()
+            .zip(argument_types.iter())
+            .position(|(expected_argument, argument_type)| {
+                return expected_argument.get_type() != argument_type;
+            })
+            .unwrap_or(0);
+        return ArgumentTypesDifferError::new(function_name.to_string(), argument_index).into();
     }
 
     fn validate_template_token(&self, token: &TemplateToken) -> Result<(), TemplateBuildError> {
         let token = token.get_field_read_token();
+        let mut input_type = None;
         for function_call in token.get_function_calls().iter() {
-            self.validate_function_call(function_call)?;
+            input_type = self.validate_function_call(function_call, input_type.as_ref())?;
         }
         return Ok(());
     }
 
-    fn validate_syntax(&self, syntax: &Syntax) -> Result<(), TemplateBuildError> {
+    pub (crate) fn validate_syntax(&self, syntax: &Syntax) -> Result<(), TemplateBuildError> {
         for token in syntax.iter_tokens() {
             match token {
                 Token::Text(..) => {},
                 Token::Template(token) => {
                     self.validate_template_token(token)?;
                 },
+                Token::UriExpression(..) => {},
+                Token::Include(include) => {
+                    let template_name = include.get_template_name().as_string_ref();
+                    return Err(TemplateBuildError::TemplateNotFound(template_name.to_string()));
+                },
             }
         }
         return Ok(());
     }
 
+    pub (crate) fn into_functions(self) -> Rc<HashMap<String, FunctionSet>> {
+        return Rc::new(self.functions);
+    }
+
     /// Creates template using passed template format.
-    pub fn build(self, format: &str) -> Result<Template, TemplateBuildError> {
+    pub fn build(mut self, format: &str) -> Result<Template, TemplateBuildError> {
+        self.take_error()?;
         let syntax = Syntax::from_str(format)?;
         self.validate_syntax(&syntax)?;
-        let template = Template::new(syntax, self.functions);
+        let template = Template::new(syntax, Rc::new(self.functions));
+        return Ok(template);
+    }
+
+    /// Creates template using passed RFC 6570 URI template format.
+    ///
+    /// Levels 1-4 are supported: simple, reserved `+`, fragment `#`, label `.`, path `/`,
+    /// path parameter `;` and query `?`/`&` expansions with explode `*` and prefix `:n` modifiers.
+    pub fn build_uri_template(mut self, format: &str) -> Result<Template, TemplateBuildError> {
+        self.take_error()?;
+        let syntax = Syntax::parse_uri_template(format)?;
+        self.validate_syntax(&syntax)?;
+        let template = Template::new(syntax, Rc::new(self.functions));
         return Ok(template);
     }
-}
\ No newline at end of file
+}
+
+impl Default for TemplateBuilder {
+    fn default() -> Self {
+        return TemplateBuilder::new();
+    }
+}
@@ -0,0 +1,40 @@
+use crate::function_executor::{Value, IntoValue};
+use std::collections::HashMap;
+
+/// Named variables passed into template execution: `Template::serialize_with_context(&value, &context)`.
+///
+/// Variables are read by function executors using `FunctionContext::get_variable`.
+#[derive(Debug, Clone)]
+pub struct TemplateContext {
+    variables: HashMap<String, Value>,
+}
+
+impl TemplateContext {
+    /// Creates context without variables.
+    pub fn new() -> TemplateContext {
+        return TemplateContext {
+            variables: HashMap::new(),
+        }
+    }
+
+    /// Sets variable: `with_variable("tenant_root", "/srv/tenants")`.
+    pub fn with_variable(mut self, name: impl Into<String>, value: impl IntoValue) -> Self {
+        let _ = self.variables.insert(name.into(), value.into_value());
+        return self;
+    }
+
+    /// Returns value of variable.
+    pub fn get_variable(&self, name: &str) -> Option<&Value> {
+        return self.variables.get(name);
+    }
+
+    pub (crate) fn get_variables(&self) -> &HashMap<String, Value> {
+        return &self.variables;
+    }
+}
+
+impl Default for TemplateContext {
+    fn default() -> Self {
+        return TemplateContext::new();
+    }
+}
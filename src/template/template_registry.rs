@@ -0,0 +1,41 @@
+use crate::template::template::Template;
+use crate::template::template_error::TemplateError;
+use std::collections::HashMap;
+use serde::Serialize;
+
+/// Set of named templates. Created by `TemplateRegistryBuilder`.
+#[derive(Debug)]
+pub struct TemplateRegistry {
+    templates: HashMap<String, Template>,
+}
+
+impl TemplateRegistry {
+    pub (crate) fn new(templates: HashMap<String, Template>) -> TemplateRegistry {
+        return TemplateRegistry {
+            templates,
+        }
+    }
+
+    /// Returns template by name. Included templates are already resolved.
+    pub fn get(&self, template_name: &str) -> Option<&Template> {
+        return self.templates.get(template_name);
+    }
+
+    /// Returns names of registered templates.
+    pub fn template_names(&self) -> impl Iterator<Item=&String> {
+        return self.templates.keys();
+    }
+
+    /// Templates the passed structure using template with passed name.
+    pub fn render<T>(&self, template_name: &str, value: &T) -> Result<String, TemplateError>
+        where
+            T: Serialize,
+    {
+        let template = self
+            .get(template_name)
+            .ok_or_else(|| {
+                return TemplateError::TemplateNotFound(template_name.to_string());
+            })?;
+        return template.serialize(value);
+    }
+}
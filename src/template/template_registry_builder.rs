@@ -0,0 +1,126 @@
+use crate::template::template_build_error::TemplateBuildError;
+use crate::template::template_builder::TemplateBuilder;
+use crate::template::template_registry::TemplateRegistry;
+use crate::template::template::Template;
+use crate::template::syntax::syntax::Syntax;
+use crate::template::syntax::token::Token;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Creates set of named templates that can include each other using `{> template_name}`.
+///
+/// ```rust
+/// use oneline_template::template::{TemplateBuilder, TemplateRegistryBuilder};
+///
+/// #[derive(serde_derive::Serialize)]
+/// struct Report {
+///   tenant: &'static str,
+///   id: u32,
+/// }
+///
+/// let registry = TemplateRegistryBuilder::new(TemplateBuilder::new())
+///   .with_template("tenant_prefix", "tenants/{self.tenant}/")
+///   .with_template("report", "{> tenant_prefix}reports/{self.id}.json")
+///   .build()
+///   .expect("Unable to create template registry");
+/// let path = registry.render("report", &Report {tenant: "acme", id: 1}).expect("Unable to format path");
+/// assert_eq!("tenants/acme/reports/1.json", &path);
+/// ```
+pub struct TemplateRegistryBuilder {
+    template_builder: TemplateBuilder,
+    formats: Vec<(String, String)>,
+}
+
+impl TemplateRegistryBuilder {
+    /// Creates template registry builder. Templates use functions of passed `template_builder`.
+    pub fn new(template_builder: TemplateBuilder) -> TemplateRegistryBuilder {
+        return TemplateRegistryBuilder {
+            template_builder,
+            formats: Vec::new(),
+        }
+    }
+
+    /// Registers template with passed name. Template registered later replaces template with the same name.
+    pub fn with_template(mut self, template_name: impl Into<String>, format: impl Into<String>) -> Self {
+        self.formats.push((template_name.into(), format.into()));
+        return self;
+    }
+
+    fn resolve(
+        template_name: &str,
+        syntaxes: &HashMap<String, Syntax>,
+        resolved: &mut HashMap<String, Syntax>,
+        chain: &mut Vec<String>,
+    ) -> Result<(), TemplateBuildError> {
+        if resolved.contains_key(template_name) {
+            return Ok(());
+        }
+        if chain.iter().any(|name| name == template_name) {
+            let mut cycle = chain.clone();
+            cycle.push(template_name.to_string());
+            return Err(TemplateBuildError::IncludeCycle(cycle));
+        }
+        let syntax = syntaxes
+            .get(template_name)
+            .ok_or_else(|| {
+                return TemplateBuildError::TemplateNotFound(template_name.to_string());
+            })?;
+        chain.push(template_name.to_string());
+        let mut tokens = Vec::new();
+        for token in syntax.iter_tokens() {
+            match token {
+                Token::Include(include) => {
+                    let included_name = include.get_template_name().as_string_ref();
+                    Self::resolve(included_name, syntaxes, resolved, chain)?;
+                    let included_syntax = &resolved[included_name];
+                    tokens.extend(included_syntax.iter_tokens().cloned());
+                },
+                token => {
+                    tokens.push(token.clone());
+                },
+            }
+        }
+        let _ = chain.pop();
+        let _ = resolved.insert(template_name.to_string(), Syntax::from_tokens(tokens));
+        return Ok(());
+    }
+
+    /// Creates template registry. Every registered template is parsed, resolved and validated.
+    pub fn build(mut self) -> Result<TemplateRegistry, TemplateBuildError> {
+        self.template_builder.take_error()?;
+        let mut syntaxes = HashMap::new();
+        let mut template_names = Vec::new();
+        for (template_name, format) in self.formats.iter() {
+            let syntax = Syntax::from_str(format)
+                .map_err(|error| {
+                    return TemplateBuildError::InvalidTemplate(template_name.clone(), Box::new(error.into()));
+                })?;
+            if syntaxes.insert(template_name.clone(), syntax).is_none() {
+                template_names.push(template_name.clone());
+            }
+        }
+        let mut resolved = HashMap::new();
+        for template_name in template_names.iter() {
+            Self::resolve(template_name, &syntaxes, &mut resolved, &mut Vec::new())
+                .map_err(|error| {
+                    return TemplateBuildError::InvalidTemplate(template_name.clone(), Box::new(error));
+                })?;
+        }
+        for template_name in template_names.iter() {
+            self.template_builder
+                .validate_syntax(&resolved[template_name])
+                .map_err(|error| {
+                    return TemplateBuildError::InvalidTemplate(template_name.clone(), Box::new(error));
+                })?;
+        }
+        let functions = self.template_builder.into_functions();
+        let templates = resolved
+            .into_iter()
+            .map(|(template_name, syntax)| {
+                let template = Template::new(syntax, functions.clone());
+                return (template_name, template);
+            })
+            .collect();
+        return Ok(TemplateRegistry::new(templates));
+    }
+}
@@ -0,0 +1,109 @@
+use oneline_template::template::Template;
+
+#[derive(serde_derive::Serialize)]
+struct Partition {
+    tenant: &'static str,
+    date: &'static str,
+}
+
+#[derive(serde_derive::Serialize)]
+struct File {
+    tenant: &'static str,
+    date: &'static str,
+    file_id: u32,
+}
+
+#[derive(serde_derive::Serialize)]
+struct FileId {
+    file_id: u32,
+}
+
+#[test]
+fn test_bind_folds_available_fields() {
+    let template = Template::parse("{self.tenant}/{self.date | string:substr(0uint, 4uint) | string:unwrap_or('----')}/{self.file_id}.json").expect("Unable to create template");
+    let partition = Partition {
+        tenant: "acme",
+        date: "2024-05-17",
+    };
+    let bound = template.bind(&partition).expect("Unable to bind template");
+    assert_eq!(bound.to_glob(), "acme/2024/*.json");
+    for file_id in 1..3 {
+        let path = bound.serialize(&FileId {file_id}).expect("Unable to format path");
+        assert_eq!(path, format!("acme/2024/{}.json", file_id));
+    }
+    let file = File {
+        tenant: "other",
+        date: "2020-01-01",
+        file_id: 7,
+    };
+    let path = template.serialize(&file).expect("Unable to format path");
+    assert_eq!(path, "other/2020/7.json");
+}
+
+#[test]
+fn test_bind_with_nothing_available() {
+    let template = Template::parse("{self.tenant}/{self.file_id}.json").expect("Unable to create template");
+    let bound = template.bind(&FileId {file_id: 1}).expect("Unable to bind template");
+    assert_eq!(bound.to_glob(), "*/1.json");
+    let bound = template.bind(&()).expect("Unable to bind template");
+    assert_eq!(bound.to_glob(), "*/*.json");
+}
+
+#[test]
+fn test_bind_propagates_function_errors() {
+    let template = Template::parse("{self.tenant | uint:hex()}/{self.file_id}").expect("Unable to create template");
+    let partition = Partition {
+        tenant: "acme",
+        date: "2024-05-17",
+    };
+    let error = template.bind(&partition).unwrap_err();
+    assert_eq!(format!("{}", error), "Trying to cast String(\"acme\") as uint");
+}
+
+#[test]
+fn test_bind_uri_template() {
+    let template = Template::parse_uri_template("/{tenant}/{date}{?file_id}").expect("Unable to create template");
+    let partition = Partition {
+        tenant: "acme",
+        date: "2024-05-17",
+    };
+    let bound = template.bind(&partition).expect("Unable to bind template");
+    assert_eq!(bound.to_glob(), "/acme/2024-05-17*");
+    let path = bound.serialize(&FileId {file_id: 3}).expect("Unable to format path");
+    assert_eq!(path, "/acme/2024-05-17?file_id=3");
+}
+
+#[derive(serde_derive::Serialize)]
+struct Upload {
+    tenant: Option<&'static str>,
+    file_id: u32,
+}
+
+#[derive(serde_derive::Serialize)]
+struct Tenant {
+    tenant: Option<&'static str>,
+}
+
+#[test]
+fn test_bind_keeps_empty_option() {
+    let template = Template::parse("{self.tenant | string:unwrap_or('default')}/{self.file_id}.json").expect("Unable to create template");
+    let bound = template.bind(&Tenant {tenant: None}).expect("Unable to bind template");
+    assert_eq!(bound.to_glob(), "*/*.json");
+    let path = bound.serialize(&Upload {tenant: Some("acme"), file_id: 1}).expect("Unable to format path");
+    assert_eq!(path, "acme/1.json");
+    let bound = template.bind(&Tenant {tenant: Some("acme")}).expect("Unable to bind template");
+    assert_eq!(bound.to_glob(), "acme/*.json");
+}
+
+#[test]
+fn test_bind_keeps_stateful_functions() {
+    let template = Template::parse("{self.file_id | seq:counter() | uint:to_string()}/{self.tenant | option:map(string:dedupe()) | string:unwrap_or('-')}").expect("Unable to create template");
+    let bound = template.bind(&Upload {tenant: Some("acme"), file_id: 1}).expect("Unable to bind template");
+    assert_eq!(bound.to_glob(), "*/*");
+    let uploads = vec![
+        Upload {tenant: Some("acme"), file_id: 1},
+        Upload {tenant: Some("acme"), file_id: 2},
+    ];
+    let paths = bound.render_batch(&uploads).expect("Unable to format paths");
+    assert_eq!(paths, vec!["1/acme", "2/acme-1"]);
+}
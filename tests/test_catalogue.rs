@@ -0,0 +1,136 @@
+use oneline_template::template::TemplateBuilder;
+use oneline_template::function_executor::*;
+use oneline_template::functions;
+
+#[test]
+fn test_functions_schemas() {
+    let catalogue = TemplateBuilder::new().functions();
+    assert!(!catalogue.is_empty());
+    let names: Vec<&String> = catalogue.iter().map(FunctionSchema::get_function_name).collect();
+    let mut sorted_names = names.clone();
+    sorted_names.sort();
+    assert_eq!(names, sorted_names);
+    let substr = catalogue.get("string:substr");
+    assert_eq!(substr.len(), 1);
+    let substr = substr[0];
+    assert_eq!(
+        substr.get_description(),
+        Some("Takes range of bytes of string. Returns `Option<String>`."),
+    );
+    assert_eq!(substr.get_input_type(), Some(&ValueType::String));
+    assert_eq!(substr.get_output_type(), Some(&ValueType::option(ValueType::String)));
+    let arguments: Vec<(Option<&str>, &str)> = substr
+        .get_arguments()
+        .iter()
+        .map(|argument| (argument.get_name(), argument.get_type_name()))
+        .collect();
+    assert_eq!(arguments, vec![(Some("offset"), "uint"), (Some("length"), "uint")]);
+    assert_eq!(substr.get_examples()[0].get_template(), "{self | string:substr(1uint, 3uint)|string:unwrap_or('-')}");
+    assert_eq!(catalogue.get("to_string").len(), 7);
+}
+
+#[test]
+fn test_functions_documented() {
+    for schema in TemplateBuilder::new().functions().iter() {
+        assert!(schema.get_description().is_some(), "Function `{}` has no description", schema.get_function_name());
+        assert!(!schema.get_examples().is_empty(), "Function `{}` has no examples", schema.get_function_name());
+        for argument in schema.get_arguments() {
+            assert!(argument.get_name().is_some(), "Argument of function `{}` has no name", schema.get_function_name());
+        }
+    }
+}
+
+#[test]
+fn test_functions_examples() {
+    let mut count = 0;
+    for schema in TemplateBuilder::new().functions().iter() {
+        for example in schema.get_examples() {
+            let template = TemplateBuilder::new()
+                .build(&example.get_template())
+                .expect("Unable to create template");
+            let output = template
+                .serialize(example.get_input())
+                .expect("Unable to format value");
+            assert_eq!(output, example.get_output(), "Example of function `{}`", schema.get_function_name());
+            count += 1;
+        }
+    }
+    assert!(count > 0);
+}
+
+#[test]
+fn test_example_with_field() {
+    let catalogue = TemplateBuilder::new().functions();
+    let example = &catalogue.get("option:or")[0].get_examples()[0];
+    assert_eq!(example.get_template(), "{self.0 | option:or(self.1)|string:unwrap_or('-')}");
+}
+
+#[test]
+fn test_custom_function_schema() {
+    let catalogue = TemplateBuilder::new_empty()
+        .with_fn("double", |value: u64, times: u64| value * times)
+        .with_function(functions::uint::Hex)
+        .functions();
+    let names: Vec<&String> = catalogue.iter().map(FunctionSchema::get_function_name).collect();
+    assert_eq!(names, vec!["double", "uint:hex"]);
+    let double = catalogue.get("double")[0];
+    assert_eq!(double.get_description(), None);
+    assert_eq!(double.get_arguments()[0].get_name(), None);
+    assert_eq!(double.get_arguments()[0].get_type_name(), "uint");
+}
+
+#[test]
+fn test_to_markdown() {
+    let catalogue = TemplateBuilder::new_empty()
+        .with_function(functions::string::Center)
+        .functions();
+    assert_eq!(catalogue.to_markdown(), concat!(
+        "## `string:center`\n",
+        "\n",
+        "Pads string from both sides. Returns `String` padded from both sides with `fill` up to `width`. Extra unit of padding goes to the right.\n",
+        "\n",
+        "Input: `string`\n",
+        "\n",
+        "Output: `string`\n",
+        "\n",
+        "| Argument | Type | Description |\n",
+        "|---|---|---|\n",
+        "| `width` | `uint` |  |\n",
+        "| `fill` | `string` |  |\n",
+        "| `measure` | `string` | measure: `chars`, `graphemes` or `width` |\n",
+        "\n",
+        "Examples:\n",
+        "\n",
+        "* `{self | string:center(5uint, '*', 'chars')}` formats `\"ab\"` as `*ab**`\n",
+        "\n",
+    ));
+}
+
+#[test]
+fn test_to_json() {
+    let catalogue = TemplateBuilder::new_empty()
+        .with_function(functions::uint::Pad)
+        .functions();
+    assert_eq!(catalogue.to_json(), r#"[
+  {
+    "name": "uint:pad",
+    "description": "Formats uint padded with leading zeroes. Returns decimal `string` padded with leading zeroes up to width.",
+    "input_type": "uint",
+    "output_type": "string",
+    "arguments": [
+      {
+        "name": "width",
+        "type": "uint",
+        "description": null
+      }
+    ],
+    "examples": [
+      {
+        "input": 7,
+        "template": "{self | uint:pad(3uint)}",
+        "output": "007"
+      }
+    ]
+  }
+]"#);
+}
@@ -0,0 +1,175 @@
+use oneline_template::template::{FieldValues, TemplateBuilder, TemplateContext};
+use oneline_template::function_executor::*;
+use serde_derive::Serialize;
+
+#[derive(Serialize)]
+struct Tenant {
+    id: u64,
+    name: String,
+}
+
+#[derive(Serialize)]
+struct File {
+    tenant: Tenant,
+    file_name: String,
+    parent_id: Option<u64>,
+}
+
+fn file() -> File {
+    return File {
+        tenant: Tenant {
+            id: 7,
+            name: "acme".to_string(),
+        },
+        file_name: "report.pdf".to_string(),
+        parent_id: None,
+    };
+}
+
+struct TenantDir;
+
+impl FunctionExecutor for TenantDir {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("lookup:tenant_dir")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+    }
+
+    fn call(&self, _value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        return Err(FunctionError::msg("Function `lookup:tenant_dir` requires function context"));
+    }
+
+    fn call_with_context(&self, value: Value, _arguments: &[Value], context: &FunctionContext) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let root = match context.get_variable("tenant_root") {
+            Some(root) => root.as_string()?.clone(),
+            None => "/srv".to_string(),
+        };
+        let tenant_id = context
+            .read_field(&["tenant", "id"])?
+            .ok_or_else(|| FunctionError::msg("Field `tenant.id` not found"))?;
+        let tenant_id = tenant_id.as_uint()?;
+        let value = format!("{}/{}/{}", root, tenant_id, value);
+        return Ok(Value::String(value));
+    }
+}
+
+struct Path;
+
+impl FunctionExecutor for Path {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("debug:path")
+            .with_output_type(ValueType::String)
+    }
+
+    fn call(&self, _value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        return Err(FunctionError::msg("Function `debug:path` requires function context"));
+    }
+
+    fn call_with_context(&self, _value: Value, _arguments: &[Value], context: &FunctionContext) -> Result<Value, FunctionError> {
+        return Ok(Value::String(context.get_path().to_string()));
+    }
+}
+
+fn builder() -> TemplateBuilder {
+    return TemplateBuilder::new()
+        .with_function(TenantDir)
+        .with_function(Path);
+}
+
+#[test]
+fn test_read_field() {
+    let template = builder()
+        .build("{self.file_name | lookup:tenant_dir()}")
+        .expect("Unable to create template");
+    assert_eq!(template.serialize(&file()).expect("Unable to format value"), "/srv/7/report.pdf");
+}
+
+#[test]
+fn test_read_missing_field() {
+    struct Missing;
+    impl FunctionExecutor for Missing {
+        fn schema(&self) -> FunctionSchema {
+            FunctionSchema::new("missing")
+                .with_output_type(ValueType::Bool)
+        }
+
+        fn call(&self, _value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+            return Ok(Value::Bool(false));
+        }
+
+        fn call_with_context(&self, _value: Value, _arguments: &[Value], context: &FunctionContext) -> Result<Value, FunctionError> {
+            let parent_id = context.read_field(&["parent_id"])?;
+            let is_missing = matches!(parent_id, Some(Value::Option(None))) && context.read_field(&["unknown"])?.is_none();
+            return Ok(Value::Bool(is_missing));
+        }
+    }
+    let template = TemplateBuilder::new()
+        .with_function(Missing)
+        .build("{self.file_name | missing() | to_string()}")
+        .expect("Unable to create template");
+    assert_eq!(template.serialize(&file()).expect("Unable to format value"), "true");
+}
+
+#[test]
+fn test_variables() {
+    let template = builder()
+        .build("{self.file_name | lookup:tenant_dir()}")
+        .expect("Unable to create template");
+    let context = TemplateContext::new()
+        .with_variable("tenant_root", "/mnt/tenants");
+    let result = template
+        .serialize_with_context(&file(), &context)
+        .expect("Unable to format value");
+    assert_eq!(result, "/mnt/tenants/7/report.pdf");
+    assert_eq!(context.get_variable("tenant_root").map(Value::as_string).and_then(Result::ok), Some(&"/mnt/tenants".to_string()));
+    assert!(context.get_variable("unknown").is_none());
+}
+
+#[test]
+fn test_path() {
+    let template = builder()
+        .build("{self.tenant.name | debug:path()}")
+        .expect("Unable to create template");
+    assert_eq!(template.serialize(&file()).expect("Unable to format value"), "tenant.name");
+    let template = builder()
+        .build("{self | debug:path()}")
+        .expect("Unable to create template");
+    assert_eq!(template.serialize(&7u32).expect("Unable to format value"), "");
+}
+
+#[test]
+fn test_context_within_function_argument() {
+    let template = builder()
+        .build("{self.parent_id | option:map(debug:path()) | unwrap_or('none')}/{self.tenant.id | uint:to_string() | lookup:tenant_dir()}")
+        .expect("Unable to create template");
+    assert_eq!(template.serialize(&file()).expect("Unable to format value"), "none//srv/7/7");
+    let file = File {
+        parent_id: Some(3),
+        ..file()
+    };
+    assert_eq!(template.serialize(&file).expect("Unable to format value"), "parent_id//srv/7/7");
+}
+
+struct Values;
+
+impl FieldValues for Values {
+    fn field_value(&self, path: &[&str]) -> Option<Value> {
+        return match path {
+            ["tenant", "id"] => Some(Value::UInt(9)),
+            ["file_name"] => Some(Value::String("a.txt".to_string())),
+            _ => None,
+        };
+    }
+}
+
+#[test]
+fn test_render_with_context() {
+    let template = builder()
+        .build("{self.file_name | lookup:tenant_dir()}")
+        .expect("Unable to create template");
+    assert_eq!(template.render(&Values).expect("Unable to format value"), "/srv/9/a.txt");
+    let context = TemplateContext::new()
+        .with_variable("tenant_root", "/data");
+    assert_eq!(template.render_with_context(&Values, &context).expect("Unable to format value"), "/data/9/a.txt");
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "macros")]
+use oneline_template::OnelineTemplate;
+use oneline_template::template::FieldValues;
+use oneline_template::function_executor::Value;
+
+#[derive(OnelineTemplate)]
+#[template("dir/{self.id | uint:pad(4uint)}/{self.name | string:slug()}.json")]
+struct File {
+    id: u32,
+    name: String,
+}
+
+struct Owner {
+    login: &'static str,
+}
+
+#[derive(OnelineTemplate)]
+#[template("{self.owner.login}/{self.nickname | option:or(self.fallback) | unwrap_or('anonymous')}/{self.data | bytes:hex()}")]
+struct Profile {
+    owner: Owner,
+    nickname: Option<String>,
+    fallback: Option<&'static str>,
+    data: Vec<u8>,
+}
+
+#[derive(OnelineTemplate)]
+#[template("{self.0}-{self.1 | to_string()}")]
+struct Pair<'a>(&'a str, bool);
+
+#[derive(OnelineTemplate)]
+#[template("{self.value | uint:div(0uint)}")]
+struct Broken {
+    value: u8,
+}
+
+#[derive(OnelineTemplate)]
+#[template("{self.value | unknown:fn()}")]
+struct UnknownFunction {
+    value: u8,
+}
+
+#[test]
+fn test_derive_render() {
+    let file = File {id: 7, name: "Hello World".to_string()};
+    assert_eq!(file.render(), "dir/0007/hello-world.json");
+    assert_eq!(File::template_format(), "dir/{self.id | uint:pad(4uint)}/{self.name | string:slug()}.json");
+    let profile = Profile {
+        owner: Owner {login: "root"},
+        nickname: None,
+        fallback: Some("admin"),
+        data: vec![1, 255],
+    };
+    assert_eq!(profile.render(), "root/admin/01ff");
+    let profile = Profile {
+        owner: Owner {login: "root"},
+        nickname: None,
+        fallback: None,
+        data: Vec::new(),
+    };
+    assert_eq!(profile.render(), "root/anonymous/");
+    assert_eq!(Pair("a", true).render(), "a-true");
+}
+
+#[test]
+fn test_derive_field_values() {
+    let file = File {id: 7, name: "a".to_string()};
+    assert!(matches!(file.field_value(&["id"]), Some(Value::UInt(7))));
+    assert!(file.field_value(&["missing"]).is_none());
+}
+
+#[test]
+fn test_derive_errors() {
+    let error = Broken {value: 1}.try_render().expect_err("Division by zero must fail");
+    assert_eq!(format!("{}", error), "Division by zero");
+}
+
+#[test]
+fn test_derive_unknown_function() {
+    let error = UnknownFunction {value: 1}.try_render().expect_err("Unknown function must fail");
+    assert_eq!(format!("{}", error), "Function with name `unknown:fn` not found");
+    let result = std::panic::catch_unwind(|| {
+        return UnknownFunction {value: 1}.render();
+    });
+    assert!(result.is_err());
+}
@@ -0,0 +1,112 @@
+use oneline_template::template::TemplateBuilder;
+use oneline_template::function_executor::*;
+use serde::Serialize;
+
+fn build(builder: TemplateBuilder, format: &str) -> oneline_template::template::Template {
+    return builder.build(format).expect("Unable to create template");
+}
+
+fn assert_ok<T>(builder: TemplateBuilder, format: &str, value: &T, expected: &str)
+    where T: Serialize
+{
+    let value = build(builder, format).serialize(value).expect("Unable to get value formatted by template");
+    assert_eq!(value, expected);
+}
+
+fn assert_err<T>(builder: TemplateBuilder, format: &str, value: &T, expected: &str)
+    where T: Serialize
+{
+    let error = build(builder, format).serialize(value).expect_err("Template must return error");
+    let error = format!("{}", error);
+    assert_eq!(error, expected);
+}
+
+fn assert_template_err(builder: TemplateBuilder, format: &str, expected: &str) {
+    let error = match builder.build(format) {
+        Ok(..) => panic!("Template must not be created"),
+        Err(error) => error,
+    };
+    let error = format!("{}", error);
+    assert_eq!(error, expected);
+}
+
+fn shout(value: String) -> String {
+    return format!("{}!", value.to_uppercase());
+}
+
+#[test]
+fn test_closures() {
+    let builder = || {
+        return TemplateBuilder::new()
+            .with_fn("string:repeat", |value: String, count: usize| -> String {
+                return value.repeat(count);
+            })
+            .with_fn("uint:clamp", |value: u32, min: u32, max: u32| -> u32 {
+                return value.clamp(min, max);
+            })
+            .with_fn("string:shout", shout);
+    };
+    assert_ok(builder(), "{self|string:repeat(3uint)}", &"ab", "ababab");
+    assert_ok(builder(), "{self|uint:clamp(1uint, 9uint)|to_string()}", &20u8, "9");
+    assert_ok(builder(), "{self|string:shout()}", &"hi", "HI!");
+    assert_ok(builder(), "{self|string:shout()|string:repeat(2uint)}", &"a", "A!A!");
+}
+
+#[test]
+fn test_conversions() {
+    let builder = || {
+        return TemplateBuilder::new()
+            .with_fn("opt:or_zero", |value: Option<i64>| -> i64 {
+                return value.unwrap_or(0);
+            })
+            .with_fn("seq:count", |value: Vec<String>| -> usize {
+                return value.len();
+            })
+            .with_fn("float:half", |value: f32| -> f64 {
+                return value as f64 / 2.0;
+            })
+            .with_fn("any:kind", |value: Value, prefix: String| -> String {
+                return format!("{}{}", prefix, ValueType::of(&value));
+            });
+    };
+    assert_ok(builder(), "{self|opt:or_zero()|to_string()}", &None::<i8>, "0");
+    assert_ok(builder(), "{self|opt:or_zero()|to_string()}", &Some(-4i8), "-4");
+    assert_ok(builder(), "{self|string:split(',')|seq:count()|to_string()}", &"a,b,c", "3");
+    assert_ok(builder(), "{self|float:half()|to_string()}", &3.0f64, "1.5");
+    assert_ok(builder(), "{self|any:kind('type: ')}", &true, "type: bool");
+    assert_err(builder(), "{self|opt:or_zero()}", &5u32, "Trying to cast UInt(5) as option");
+}
+
+#[test]
+fn test_errors() {
+    let builder = || {
+        return TemplateBuilder::new()
+            .with_fn("string:parse_uint", |value: String| -> Result<u64, std::num::ParseIntError> {
+                return value.parse();
+            })
+            .with_fn("uint:small", |value: u8| -> u8 {
+                return value;
+            });
+    };
+    assert_ok(builder(), "{self|string:parse_uint()|hex()}", &"255", "ff");
+    assert_err(builder(), "{self|string:parse_uint()|to_string()}", &"x", "invalid digit found in string");
+    assert_err(builder(), "{self|uint:small()|to_string()}", &300u32, "Unable to convert uint 300 into u8");
+    assert_template_err(builder(), "{self|string:parse_uint()|len()}", "Function `len` does not accept input of type `uint`");
+}
+
+#[test]
+fn test_schema_is_derived() {
+    let function_executor = FnExecutor::new("string:pad", |value: String, width: usize, fill: String| -> String {
+        return format!("{}{}", fill.repeat(width.saturating_sub(value.len())), value);
+    });
+    let schema = function_executor.schema();
+    assert_eq!(schema.get_function_name(), "string:pad");
+    assert_eq!(schema.get_input_type(), Some(&ValueType::String));
+    assert_eq!(schema.get_output_type(), Some(&ValueType::String));
+    assert_eq!(schema.get_arguments().len(), 2);
+    assert_template_err(TemplateBuilder::new().with_function(function_executor), "{self|string:pad('1')}", "Into function `string:pad` passed arguments with wrong length");
+    let function_executor = FnExecutor::new("string:pad", |value: String, width: usize, fill: String| -> String {
+        return format!("{}{}", fill.repeat(width.saturating_sub(value.len())), value);
+    });
+    assert_template_err(TemplateBuilder::new().with_function(function_executor), "{self|string:pad('1', '0')}", "Argument with index `0` at function `string:pad` has wrong type");
+}
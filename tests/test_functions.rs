@@ -1,4 +1,6 @@
 use oneline_template::template::Template;
+use oneline_template::function_executor::{FunctionExecutor, Value};
+use oneline_template::functions;
 use serde::Serialize;
 
 fn assert_ok<T>(format: &str, value: &T, expected: &str)
@@ -18,6 +20,12 @@ fn assert_err<T>(format: &str, value: &T, expected: &str)
     assert_eq!(error, expected);
 }
 
+fn assert_build_err(format: &str, expected: &str) {
+    let error = Template::parse(format).expect_err("Template must not be created");
+    let error = format!("{}", error);
+    assert_eq!(error, expected);
+}
+
 #[test]
 fn test_bool() {
     assert_err("{self}", &true, "Convertation bool to string is not supported");
@@ -36,6 +44,58 @@ fn test_func_bool_unwrap_or() {
     assert_ok("{self|bool:unwrap_or(true)|bool:to_string()}", &value, "true");
 }
 
+#[test]
+fn test_func_bytes_hex() {
+    let value = vec![0x00u8, 0x0f, 0xab, 0xff];
+    assert_ok("{self|bytes:hex()}", &value, "000fabff");
+    let value: Vec<u8> = Vec::new();
+    assert_ok("{self|bytes:hex()}", &value, "");
+    assert_err("{self|bytes:hex()}", &"abc", "Trying to cast String(\"abc\") as bytes");
+}
+
+#[test]
+fn test_func_bytes_encodings() {
+    let value = b"foobar".to_vec();
+    assert_ok("{self|bytes:hex_upper()}", &value, "666F6F626172");
+    assert_ok("{self|bytes:base64()}", &value, "Zm9vYmFy");
+    assert_ok("{self|bytes:base32()}", &value, "MZXW6YTBOI======");
+    assert_ok("{self|bytes:base58()}", &value, "t1Zv2yaZ");
+    let value = vec![0xfbu8, 0xff, 0xfe];
+    assert_ok("{self|bytes:base64()}", &value, "+//+");
+    assert_ok("{self|bytes:base64url()}", &value, "-__-");
+    let value = vec![0xfbu8, 0xff];
+    assert_ok("{self|bytes:base64()}", &value, "+/8=");
+    assert_ok("{self|bytes:base64url()}", &value, "-_8");
+}
+
+#[test]
+fn test_func_bytes_len() {
+    let value = vec![0x00u8, 0x0f, 0xab];
+    assert_ok("{self|bytes:len()}", &value, "3");
+    let value: Vec<u8> = Vec::new();
+    assert_ok("{self|bytes:len()}", &value, "0");
+}
+
+#[test]
+fn test_func_bytes_slice() {
+    let value = vec![0x00u8, 0x0f, 0xab, 0xff];
+    assert_ok("{self|bytes:slice(1uint, 2uint)|bytes:hex()}", &value, "0fab");
+    assert_ok("{self|bytes:slice(2uint, 10uint)|bytes:hex()}", &value, "abff");
+    assert_ok("{self|bytes:slice(10uint, 2uint)|bytes:hex()}", &value, "");
+}
+
+#[test]
+fn test_func_string_decode() {
+    assert_ok("{self|string:decode_hex()|bytes:base64()}", &"666f6F626172", "Zm9vYmFy");
+    assert_ok("{self|string:decode_base64()|bytes:hex()}", &"+/8=", "fbff");
+    assert_ok("{self|string:decode_base64url()|bytes:hex()}", &"-_8", "fbff");
+    assert_ok("{self|string:decode_base32()|bytes:hex()}", &"MZXW6YTBOI======", "666f6f626172");
+    assert_ok("{self|string:decode_base58()|bytes:hex()}", &"t1Zv2yaZ", "666f6f626172");
+    assert_ok("{self|string:decode_hex()}", &"666f6f", "foo");
+    assert_err("{self|string:decode_hex()}", &"abc", "invalid length at 2");
+    assert_err("{self|string:decode_base58()}", &"0", "provided string contained invalid character '0' at byte 0");
+}
+
 #[test]
 fn test_float() {
     assert_err("{self}", &0.5f32, "Convertation float to string is not supported");
@@ -151,4 +211,269 @@ fn test_func_debug_type() {
     assert_ok("{self|debug:type()}", &5u32, "UInt");
     assert_ok("{self|debug:type()}", &5i32, "Int");
     assert_ok("{self|debug:type()}", &35.0f64, "Float");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_func_string_case() {
+    assert_ok("{self|string:lower()}", &"Hello World", "hello world");
+    assert_ok("{self|string:upper()}", &"Hello World", "HELLO WORLD");
+    assert_ok("{self|string:lower()}", &"ПРИВЕТ Straße", "привет straße");
+    assert_ok("{self|string:upper()}", &"привет straße", "ПРИВЕТ STRASSE");
+    assert_ok("{self|string:snake_case()}", &"Hello World", "hello_world");
+    assert_ok("{self|string:snake_case()}", &"helloWorld", "hello_world");
+    assert_ok("{self|string:snake_case()}", &"Привет Мир", "привет_мир");
+    assert_ok("{self|string:kebab_case()}", &"Hello World", "hello-world");
+    assert_ok("{self|string:kebab_case()}", &"ПриветМир", "привет-мир");
+    assert_ok("{self|string:camel_case()}", &"hello_world", "helloWorld");
+    assert_ok("{self|string:camel_case()}", &"привет мир", "приветМир");
+    assert_ok("{self|string:title_case()}", &"hello_world", "Hello World");
+    assert_ok("{self|string:title_case()}", &"ελληνικά γράμματα", "Ελληνικά Γράμματα");
+}
+
+#[test]
+fn test_func_string_slug() {
+    assert_ok("{self|string:slug()}", &"Hello, World!", "hello-world");
+    assert_ok("{self|string:slug()}", &"  --Don't  panic__now-- ", "don-t-panic-now");
+    assert_ok("{self|string:slug()}", &"a,b", "a-b");
+    assert_ok("{self|string:slug()}", &"a+b=c;d", "a-b-c-d");
+    assert_ok("{self|string:slug()}", &"Crème brûlée", "creme-brulee");
+    assert_ok("{self|string:slug()}", &"Привет, мир", "privet-mir");
+    assert_ok("{self|string:slug()}", &"北京", "bei-jing");
+    assert_ok("{self|string:slug()}", &"!!!", "");
+}
+
+#[test]
+fn test_func_string_pad() {
+    assert_ok("{self|string:pad_left(5uint, '.', 'chars')}", &"ab", "...ab");
+    assert_ok("{self|string:pad_right(5uint, '.', 'chars')}", &"ab", "ab...");
+    assert_ok("{self|string:center(5uint, '.', 'chars')}", &"ab", ".ab..");
+    assert_ok("{self|string:pad_left(5uint, '-=', 'chars')}", &"ab", "-=-ab");
+    assert_ok("{self|string:pad_left(2uint, '.', 'chars')}", &"abc", "abc");
+    assert_ok("{self|string:pad_left(4uint, '.', 'chars')}", &"жук", ".жук");
+    assert_ok("{self|string:pad_left(4uint, '.', 'chars')}", &"e\u{301}", "..e\u{301}");
+    assert_ok("{self|string:pad_left(4uint, '.', 'graphemes')}", &"e\u{301}", "...e\u{301}");
+    assert_ok("{self|string:pad_left(4uint, '.', 'width')}", &"日本", "日本");
+    assert_ok("{self|string:pad_right(6uint, '.', 'width')}", &"日本", "日本..");
+    assert_ok("{self|string:pad_right(5uint, '日', 'width')}", &"ab", "ab日");
+    assert_err("{self|string:pad_left(4uint, '', 'chars')}", &"ab", "Fill string must not be empty");
+    assert_ok("{self|string:pad_left(3uint, '.', 'width')}", &"\u{644}\u{627}", ".\u{644}\u{627}");
+    assert_build_err("{self|string:pad_left(4uint, '.', 'bytes')}", "Function `string:pad_left` rejected arguments: Unknown measure `bytes`, expected `chars`, `graphemes` or `width`");
+    assert_build_err("{self|string:pad_left(18446744073709551615uint, '.', 'chars')}", "Function `string:pad_left` rejected arguments: Argument `width` must not exceed 65535, got 18446744073709551615");
+    let error = functions::string::PadRight
+        .call(Value::String("ab".to_string()), &[Value::UInt(100000), Value::String(".".to_string()), Value::String("chars".to_string())])
+        .expect_err("Width must be rejected");
+    assert_eq!(format!("{}", error), "Argument `width` must not exceed 65535, got 100000");
+    assert_build_err("{self|string:center(4uint, '.', 'bytes')}", "Function `string:center` rejected arguments: Unknown measure `bytes`, expected `chars`, `graphemes` or `width`");
+}
+
+#[test]
+fn test_func_string_truncate() {
+    assert_ok("{self|string:truncate(5uint, '...', 'chars')}", &"hello world", "he...");
+    assert_ok("{self|string:truncate(11uint, '...', 'chars')}", &"hello world", "hello world");
+    assert_ok("{self|string:truncate(2uint, '...', 'chars')}", &"hello world", "..");
+    assert_ok("{self|string:truncate(4uint, '…', 'chars')}", &"привет", "при…");
+    assert_ok("{self|string:truncate(2uint, '', 'graphemes')}", &"e\u{301}e\u{301}e\u{301}", "e\u{301}e\u{301}");
+    assert_ok("{self|string:truncate(5uint, '…', 'width')}", &"日本語です", "日本…");
+    assert_ok("{self|string:truncate(2uint, '', 'width')}", &"\u{644}\u{627}", "\u{644}\u{627}");
+    assert_build_err("{self|string:truncate(2uint, '', 'bytes')}", "Function `string:truncate` rejected arguments: Unknown measure `bytes`, expected `chars`, `graphemes` or `width`");
+}
+
+#[test]
+fn test_func_pad_number() {
+    assert_ok("{self|uint:pad(4uint)}", &42u32, "0042");
+    assert_ok("{self|uint:pad(1uint)}", &42u32, "42");
+    assert_ok("{self|int:pad(4uint)}", &-5i32, "-005");
+    assert_ok("{self|int:pad(4uint)}", &5i32, "0005");
+    assert_ok("{self|uint:pad(65535uint)|string:chars(-2int, 2uint)}", &42u32, "42");
+    assert_build_err("{self|uint:pad(100000uint)}", "Function `uint:pad` rejected arguments: Argument `width` must not exceed 65535, got 100000");
+    assert_build_err("{self|pad(100000uint)}", "Function `pad` rejected arguments: Argument `width` must not exceed 65535, got 100000");
+    let error = functions::int::Pad
+        .call(Value::Int(1), &[Value::UInt(100000)])
+        .expect_err("Width must be rejected");
+    assert_eq!(format!("{}", error), "Argument `width` must not exceed 65535, got 100000");
+}
+
+#[test]
+fn test_func_string_chars() {
+    assert_ok("{self|string:chars(0int, 3uint)}", &"Светлана", "Све");
+    assert_ok("{self|string:chars(-4int, 2uint)}", &"Светлана", "ла");
+    assert_ok("{self|string:chars(-20int, 2uint)}", &"Светлана", "Св");
+    assert_ok("{self|string:chars(6int, 20uint)}", &"Светлана", "на");
+    assert_ok("{self|string:chars(20int, 2uint)}", &"Светлана", "");
+    assert_ok("{self|string:chars(1int, 340282366920938463463374607431768211455uint)}", &"abc", "bc");
+    assert_ok("{self|string:chars(0int, 1uint)}", &"e\u{301}x", "e");
+}
+
+#[test]
+fn test_func_string_graphemes() {
+    assert_ok("{self|string:graphemes(0int, 1uint)}", &"e\u{301}x", "e\u{301}");
+    assert_ok("{self|string:graphemes(-1int, 1uint)}", &"xe\u{301}", "e\u{301}");
+    assert_ok("{self|string:graphemes(1int, 1uint)}", &"🇷🇺🇺🇦", "🇺🇦");
+}
+
+#[test]
+fn test_func_string_substr_overflow() {
+    assert_ok("{self|string:substr(1uint, 340282366920938463463374607431768211455uint)|string:unwrap_or('--')}", &"abc", "--");
+}
+
+#[test]
+fn test_func_string_replace() {
+    assert_ok("{self|string:replace('/', '_')}", &"a/b/c", "a_b_c");
+    assert_ok("{self|string:replace_n('/', '_', 1uint)}", &"a/b/c", "a_b/c");
+    assert_ok("{self|string:replace_n('/', '_', 0uint)}", &"a/b/c", "a/b/c");
+}
+
+#[test]
+fn test_func_string_strip() {
+    assert_ok("{self|string:strip_prefix('v')}", &"v1.2", "1.2");
+    assert_ok("{self|string:strip_prefix('v')}", &"1.2", "1.2");
+    assert_ok("{self|string:strip_suffix('.json')}", &"report.json", "report");
+    assert_ok("{self|string:strip_suffix('.json')}", &"report", "report");
+}
+
+#[test]
+fn test_func_string_predicates() {
+    assert_ok("{self|string:contains('ell')|bool:to_string()}", &"hello", "true");
+    assert_ok("{self|string:contains('xyz')|bool:to_string()}", &"hello", "false");
+    assert_ok("{self|string:starts_with('he')|bool:to_string()}", &"hello", "true");
+    assert_ok("{self|string:ends_with('he')|bool:to_string()}", &"hello", "false");
+}
+
+#[test]
+fn test_func_string_split() {
+    assert_ok("{self|string:split(',')|seq:join('/')}", &"a,b,c", "a/b/c");
+    assert_ok("{self|string:split(',')|seq:len()}", &"a,b,c", "3");
+    assert_ok("{self|string:split(',')|seq:get(1int)|string:unwrap_or('-')}", &"a,b,c", "b");
+    assert_ok("{self|string:split(',')|seq:get(-1int)|string:unwrap_or('-')}", &"a,b,c", "c");
+    assert_ok("{self|string:split(',')|seq:get(3int)|string:unwrap_or('-')}", &"a,b,c", "-");
+    assert_err("{self|string:split(',')}", &"a,b,c", "Convertation seq to string is not supported");
+}
+
+#[test]
+fn test_seq() {
+    let value = vec!["a".to_string(), "b".to_string()];
+    assert_ok("{self|seq:join('-')}", &value, "a-b");
+    let value = vec![1u32, 2, 3];
+    assert_ok("{self|seq:join('-')}", &value, "1-2-3");
+    let value = vec![true];
+    assert_err("{self|seq:join('-')}", &value, "Unable to join Bool(true)");
+}
+
+#[test]
+fn test_func_uint_radix() {
+    assert_ok("{self|uint:radix(36uint)}", &35u32, "z");
+    assert_ok("{self|uint:radix(36uint)}", &36u32, "10");
+    assert_ok("{self|uint:radix(2uint)}", &0u32, "0");
+    assert_ok("{self|uint:radix(16uint)}", &u128::MAX, "ffffffffffffffffffffffffffffffff");
+    assert_ok("{self|uint:octal()}", &8u32, "10");
+    assert_ok("{self|uint:binary()}", &5u32, "101");
+    let error = Template::parse("{self|uint:radix(37uint)}").expect_err("Template must not be created");
+    assert_eq!(format!("{}", error), "Function `uint:radix` rejected arguments: Base must be from 2 to 36, got 37");
+}
+
+#[test]
+fn test_func_uint_thousands() {
+    assert_ok("{self|uint:thousands(',')}", &1234567u32, "1,234,567");
+    assert_ok("{self|uint:thousands(' ')}", &123456u32, "123 456");
+    assert_ok("{self|uint:thousands(',')}", &999u32, "999");
+    assert_ok("{self|uint:thousands(',')}", &0u32, "0");
+}
+
+#[test]
+fn test_func_float_format() {
+    assert_ok("{self|float:fixed(2uint)}", &1.23456f64, "1.23");
+    assert_ok("{self|float:fixed(0uint)}", &2.5f64, "2");
+    assert_ok("{self|float:sci(2uint)}", &12345.0f64, "1.23e4");
+    assert_ok("{self|float:percent(1uint)}", &0.125f64, "12.5%");
+    assert_build_err("{self|float:fixed(100000uint)}", "Function `float:fixed` rejected arguments: Argument `precision` must not exceed 65535, got 100000");
+    assert_build_err("{self|float:sci(100000uint)}", "Function `float:sci` rejected arguments: Argument `precision` must not exceed 65535, got 100000");
+    assert_build_err("{self|float:percent(100000uint)}", "Function `float:percent` rejected arguments: Argument `precision` must not exceed 65535, got 100000");
+    let error = functions::float::Fixed
+        .call(Value::Float(1.5), &[Value::UInt(100000)])
+        .expect_err("Precision must be rejected");
+    assert_eq!(format!("{}", error), "Argument `precision` must not exceed 65535, got 100000");
+}
+
+#[test]
+fn test_func_float_round() {
+    assert_ok("{self|float:round()}", &2.5f64, "3");
+    assert_ok("{self|float:round()}", &-2.5f64, "-3");
+    assert_ok("{self|float:floor()}", &-2.5f64, "-3");
+    assert_ok("{self|float:ceil()}", &2.1f64, "3");
+    assert_err("{self|float:round()}", &f64::NAN, "Unable to convert float NaN into int");
+    assert_err("{self|float:ceil()}", &1e40f64, "Unable to convert float 10000000000000000000000000000000000000000 into int");
+}
+
+#[test]
+fn test_func_uint_arithmetic() {
+    assert_ok("{self|uint:add(1uint)}", &5u32, "6");
+    assert_ok("{self|uint:sub(1uint)}", &5u32, "4");
+    assert_ok("{self|uint:mul(3uint)}", &5u32, "15");
+    assert_ok("{self|uint:div(1000uint)}", &123456u32, "123");
+    assert_ok("{self|uint:rem(1000uint)}", &123456u32, "456");
+    assert_err("{self|uint:sub(6uint)}", &5u32, "Overflow when computing 5 - 6");
+    assert_err("{self|uint:add(1uint)}", &u128::MAX, "Overflow when computing 340282366920938463463374607431768211455 + 1");
+    assert_err("{self|uint:div(0uint)}", &5u32, "Division by zero");
+    assert_err("{self|uint:rem(0uint)}", &5u32, "Division by zero");
+}
+
+#[test]
+fn test_func_int_arithmetic() {
+    assert_ok("{self|int:add(-10int)}", &5i32, "-5");
+    assert_ok("{self|int:sub(10int)}", &5i32, "-5");
+    assert_ok("{self|int:mul(-3int)}", &5i32, "-15");
+    assert_ok("{self|int:div(2int)}", &-7i32, "-3");
+    assert_ok("{self|int:rem(2int)}", &-7i32, "-1");
+    assert_err("{self|int:div(-1int)}", &i128::MIN, "Overflow when computing -170141183460469231731687303715884105728 / -1");
+    assert_err("{self|int:div(0int)}", &5i32, "Division by zero");
+}
+
+#[test]
+fn test_func_float_arithmetic() {
+    assert_ok("{self|float:add(1.5float)|float:to_string()}", &1.0f64, "2.5");
+    assert_ok("{self|float:sub(-1.5float)|float:to_string()}", &1.0f64, "2.5");
+    assert_ok("{self|float:mul(2float)|float:to_string()}", &1.25f64, "2.5");
+    assert_ok("{self|float:div(4float)|float:to_string()}", &10.0f64, "2.5");
+    assert_ok("{self|float:rem(3float)|float:to_string()}", &5.5f64, "2.5");
+    assert_err("{self|float:div(0float)}", &1.0f64, "Result of 1 / 0 is not finite");
+}
+
+#[test]
+fn test_func_numeric_casts() {
+    assert_ok("{self|uint:to_int()|int:sub(10int)}", &5u32, "-5");
+    assert_err("{self|uint:to_int()}", &u128::MAX, "Unable to convert uint 340282366920938463463374607431768211455 into int");
+    assert_ok("{self|int:to_uint()|uint:add(1uint)}", &5i32, "6");
+    assert_err("{self|int:to_uint()}", &-5i32, "Unable to convert int -5 into uint");
+    assert_ok("{self|uint:to_float()|float:div(2float)|float:to_string()}", &5u32, "2.5");
+    assert_ok("{self|int:to_float()|float:div(2float)|float:to_string()}", &-5i32, "-2.5");
+    assert_ok("{self|float:to_int()}", &-2.9f64, "-2");
+    assert_err("{self|float:to_int()}", &f64::INFINITY, "Unable to convert float inf into int");
+}
+
+#[test]
+fn test_func_human_bytes() {
+    assert_ok("{self|uint:bytes_human('iec')}", &0u32, "0B");
+    assert_ok("{self|uint:bytes_human('iec')}", &1023u32, "1023B");
+    assert_ok("{self|uint:bytes_human('iec')}", &1024u32, "1KiB");
+    assert_ok("{self|uint:bytes_human('iec')}", &1572864u32, "1.5MiB");
+    assert_ok("{self|uint:bytes_human('iec')}", &1048575u32, "1MiB");
+    assert_ok("{self|uint:bytes_human('si')}", &1500000u32, "1.5MB");
+    assert_ok("{self|uint:bytes_human('si')}", &999u32, "999B");
+    assert_ok("{self|uint:bytes_human('si')}", &10u128.pow(27), "1000YB");
+    let error = Template::parse("{self|uint:bytes_human('metric')}").expect_err("Template must not be created");
+    assert_eq!(format!("{}", error), "Function `uint:bytes_human` rejected arguments: Unknown unit system `metric`, expected `iec` or `si`");
+}
+
+#[test]
+fn test_func_human_duration() {
+    assert_ok("{self|uint:duration_secs()}", &5400u32, "1h30m");
+    assert_ok("{self|uint:duration_secs()}", &0u32, "0s");
+    assert_ok("{self|uint:duration_secs()}", &93784u32, "1d2h3m4s");
+    assert_ok("{self|string:parse_duration()}", &"1h30m", "5400");
+    assert_ok("{self|string:parse_duration()}", &"2w1s", "1209601");
+    assert_ok("{self|string:parse_duration()|uint:duration_secs()}", &"90m", "1h30m");
+    assert_err("{self|string:parse_duration()}", &"", "Unable to parse duration ``");
+    assert_err("{self|string:parse_duration()}", &"10", "Unable to parse duration `10`");
+    assert_err("{self|string:parse_duration()}", &"h", "Unable to parse duration `h`");
+    assert_err("{self|string:parse_duration()}", &"1y", "Unable to parse duration `1y`");
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "hash")]
+use oneline_template::template::Template;
+use serde::Serialize;
+
+fn assert_ok<T>(format: &str, value: &T, expected: &str)
+    where T: Serialize
+{
+    let template = Template::parse(format).expect("Unable to create template");
+    let value = template.serialize(value).expect("Unable to get value formatted by template");
+    assert_eq!(value, expected);
+}
+
+fn assert_err<T>(format: &str, value: &T, expected: &str)
+    where T: Serialize
+{
+    let template = Template::parse(format).expect("Unable to create template");
+    let error = template.serialize(value).expect_err("Unable to get value formatted by template");
+    let error = format!("{}", error);
+    assert_eq!(error, expected);
+}
+
+#[test]
+fn test_func_hash_sha256() {
+    assert_ok("{self|hash:sha256()|bytes:hex()}", &"abc", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    let value = vec![0x61u8, 0x62, 0x63];
+    assert_ok("{self|hash:sha256()|bytes:hex()}", &value, "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    assert_err("{self|hash:sha256()}", &5u32, "Trying to cast UInt(5) as string or bytes");
+}
+
+#[test]
+fn test_func_hash_md5() {
+    assert_ok("{self|hash:md5()|bytes:hex()}", &"abc", "900150983cd24fb0d6963f7d28e17f72");
+}
+
+#[test]
+fn test_func_hash_crc32() {
+    assert_ok("{self|hash:crc32()}", &"abc", "891568578");
+    assert_ok("{self|hash:crc32()|uint:hex_fmt(8uint)}", &"abc", "352441c2");
+}
+
+#[test]
+fn test_func_hash_xxh3() {
+    assert_ok("{self|hash:xxh3()|uint:hex()}", &"abc", "78af5f94892f3950");
+}
+
+#[test]
+fn test_func_hash_fnv1a() {
+    assert_ok("{self|hash:fnv1a()}", &"abc", "16654208175385433931");
+}
+
+#[test]
+fn test_sharded_path() {
+    let format = "{self|hash:sha256()|bytes:hex()|string:substr(0uint,2uint)|string:unwrap_or('')}/{self|hash:sha256()|bytes:hex()|string:substr(2uint,2uint)|string:unwrap_or('')}/{self}";
+    assert_ok(format, &"abc", "ba/78/abc");
+}
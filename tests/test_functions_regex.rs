@@ -0,0 +1,37 @@
+#![cfg(feature = "regex")]
+use oneline_template::template::Template;
+use serde::Serialize;
+
+fn assert_ok<T>(format: &str, value: &T, expected: &str)
+    where T: Serialize
+{
+    let template = Template::parse(format).expect("Unable to create template");
+    let value = template.serialize(value).expect("Unable to get value formatted by template");
+    assert_eq!(value, expected);
+}
+
+fn assert_build_err(format: &str, expected: &str) {
+    let error = Template::parse(format).expect_err("Template must not be created");
+    let error = format!("{}", error);
+    assert_eq!(error, expected);
+}
+
+#[test]
+fn test_func_regex_replace() {
+    assert_ok("{self|regex:replace('\\d+', '#')}", &"a1b22c333", "a#b#c#");
+    assert_ok("{self|regex:replace('(\\w+)@(\\w+)', '$2 at $1')}", &"user@host", "host at user");
+    assert_ok("{self|regex:replace('x', 'y')}", &"abc", "abc");
+}
+
+#[test]
+fn test_func_regex_capture() {
+    assert_ok("{self|regex:capture('v(\\d+)', 1uint)|string:unwrap_or('-')}", &"release-v12", "12");
+    assert_ok("{self|regex:capture('v(\\d+)', 0uint)|string:unwrap_or('-')}", &"release-v12", "v12");
+    assert_ok("{self|regex:capture('v(\\d+)', 1uint)|string:unwrap_or('-')}", &"release", "-");
+}
+
+#[test]
+fn test_func_regex_validation() {
+    assert_build_err("{self|regex:replace('(', '')}", "Function `regex:replace` rejected arguments: regex parse error:\n    (\n    ^\nerror: unclosed group");
+    assert_build_err("{self|regex:capture('v(\\d+)', 2uint)}", "Function `regex:capture` rejected arguments: Capture group `2` not found");
+}
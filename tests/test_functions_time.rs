@@ -0,0 +1,77 @@
+#![cfg(feature = "time")]
+use oneline_template::template::Template;
+use serde::Serialize;
+
+fn assert_ok<T>(format: &str, value: &T, expected: &str)
+    where T: Serialize
+{
+    let template = Template::parse(format).expect("Unable to create template");
+    let value = template.serialize(value).expect("Unable to get value formatted by template");
+    assert_eq!(value, expected);
+}
+
+fn assert_err<T>(format: &str, value: &T, expected: &str)
+    where T: Serialize
+{
+    let template = Template::parse(format).expect("Unable to create template");
+    let error = template.serialize(value).expect_err("Unable to get value formatted by template");
+    let error = format!("{}", error);
+    assert_eq!(error, expected);
+}
+
+fn assert_build_err(format: &str, expected: &str) {
+    let error = Template::parse(format).expect_err("Template must not be created");
+    let error = format!("{}", error);
+    assert_eq!(error, expected);
+}
+
+#[test]
+fn test_func_time_from_unix_secs() {
+    assert_ok("{self|time:from_unix_secs()}", &1715940000u64, "2024-05-17T10:00:00Z");
+    assert_ok("{self|time:from_unix_secs()}", &-86400i64, "1969-12-31T00:00:00Z");
+    assert_err("{self|time:from_unix_secs()}", &"1715940000", "Trying to cast String(\"1715940000\") as unix timestamp");
+}
+
+#[test]
+fn test_func_time_from_unix_millis() {
+    assert_ok("{self|time:from_unix_millis()}", &1715940000123u64, "2024-05-17T10:00:00.123Z");
+}
+
+#[test]
+fn test_func_time_format() {
+    assert_ok("year={self|time:format('%Y/month=%m/day=%d')}", &1715940000u64, "year=2024/month=05/day=17");
+    assert_ok("{self|time:format('%Y/%m/%d')}", &"2024-05-17T23:30:00+03:00", "2024/05/17");
+    assert_ok("{self|time:format('%Y/%m/%d %H')}", &"2024-05-17T10:15:00", "2024/05/17 10");
+    assert_ok("{self|time:format('%Y/%m/%d %H')}", &"2024-05-17", "2024/05/17 00");
+    assert_err("{self|time:format('%Y/%m/%d')}", &"yesterday", "Unable to read date and time from \"yesterday\"");
+    assert_build_err("{self|time:format('%Q')}", "Function `time:format` rejected arguments: Invalid date and time format \"%Q\"");
+}
+
+#[test]
+fn test_func_time_format_with_offset() {
+    assert_ok("{self|time:format('%Y/%m/%d %H:%M', '+03:00')}", &1715981400u64, "2024/05/18 00:30");
+    assert_ok("{self|time:format('%Y/%m/%d %H:%M %z', '-0530')}", &"2024-05-17T00:00:00Z", "2024/05/16 18:30 -0530");
+    assert_ok("{self|time:format('%H:%M', 'Z')}", &"2024-05-17T02:00:00+03:00", "23:00");
+    assert_build_err("{self|time:format('%Y', 'Moscow')}", "Function `time:format` rejected arguments: Unable to read timezone offset from \"Moscow\"");
+    assert_build_err("{self|time:format('%Q', '+03:00')}", "Function `time:format` rejected arguments: Invalid date and time format \"%Q\"");
+}
+
+#[test]
+fn test_func_time_with_offset() {
+    assert_ok("{self|time:with_offset('+03:00')}", &1715974200u64, "2024-05-17T22:30:00+03:00");
+    assert_ok("{self|time:with_offset('+03:00')|time:format('%Y/%m/%d')}", &1715981400u64, "2024/05/18");
+    assert_ok("{self|time:with_offset('-0530')|time:format('%Y/%m/%d %H:%M')}", &"2024-05-17T00:00:00Z", "2024/05/16 18:30");
+    assert_ok("{self|time:with_offset('Z')}", &"2024-05-17T02:00:00+03:00", "2024-05-16T23:00:00Z");
+    assert_build_err("{self|time:with_offset('Moscow')}", "Function `time:with_offset` rejected arguments: Unable to read timezone offset from \"Moscow\"");
+}
+
+#[test]
+fn test_func_time_parts() {
+    let value = "2024-05-17T10:00:00Z";
+    assert_ok("{self|time:year()}", &value, "2024");
+    assert_err("{self|time:year()}", &-100000000000i64, "Year -1199 is before year 0 and can not be returned as uint");
+    assert_ok("{self|time:month()|uint:hex_fmt(2uint)}", &value, "05");
+    assert_ok("{self|time:day()}", &value, "17");
+    assert_ok("{self|time:iso_week()}", &value, "20");
+    assert_ok("{self|time:iso_week()}", &"2021-01-03", "53");
+}
@@ -0,0 +1,129 @@
+use oneline_template::template::{OverwritePolicy, TemplateBuilder, TemplateRegistryBuilder};
+use oneline_template::function_executor::*;
+use oneline_template::functions;
+
+fn format(builder: TemplateBuilder, format: &str, value: u32) -> String {
+    let template = builder.build(format).expect("Unable to create template");
+    return template.serialize(&value).expect("Unable to format value");
+}
+
+fn build_error(builder: TemplateBuilder, format: &str) -> String {
+    return match builder.build(format) {
+        Ok(..) => panic!("Template must not be created"),
+        Err(error) => format!("{}", error),
+    };
+}
+
+struct Upper;
+
+impl FunctionExecutor for Upper {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("uint:hex")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::String)
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let value = Value::String(format!("{:X}", value));
+        return Ok(value);
+    }
+}
+
+#[test]
+fn test_with_module() {
+    let builder = TemplateBuilder::new_empty()
+        .with_module(functions::uint::module());
+    assert_eq!(format(builder, "{self|uint:hex()}", 255), "ff");
+    let builder = TemplateBuilder::new_empty()
+        .with_module(functions::uint::module());
+    assert_eq!(build_error(builder, "{self|hex()}"), "Function with name `hex` not found");
+    let builder = TemplateBuilder::new_empty()
+        .with_module(functions::uint::module())
+        .with_module(functions::generic::module());
+    assert_eq!(format(builder, "{self|hex()}", 255), "ff");
+    let module = functions::string::module();
+    assert_eq!(module.get_name(), "string");
+    assert!(module.iter().any(|function_executor| function_executor.schema().get_function_name() == "string:slug"));
+}
+
+#[test]
+fn test_without_namespace() {
+    let builder = TemplateBuilder::new()
+        .without_namespace("debug");
+    assert_eq!(build_error(builder, "{self|debug:type()}"), "Function with name `debug:type` not found");
+    let builder = TemplateBuilder::new()
+        .without_namespace("uint");
+    assert_eq!(build_error(builder, "{self|uint:hex()}"), "Function with name `uint:hex` not found");
+    let builder = TemplateBuilder::new()
+        .without_namespace("uint");
+    assert_eq!(format(builder, "{self|to_string()}", 5), "5");
+    let builder = TemplateBuilder::new()
+        .without_namespace("human");
+    assert_eq!(build_error(builder, "{self|uint:bytes_human()}"), "Function with name `uint:bytes_human` not found");
+    let builder = TemplateBuilder::new()
+        .without_namespace("human");
+    assert_eq!(format(builder, "{self|uint:hex()}", 255), "ff");
+    let builder = TemplateBuilder::new()
+        .without_namespace("generic");
+    assert_eq!(build_error(builder, "{self|to_string()}"), "Function with name `to_string` not found");
+}
+
+#[test]
+fn test_with_alias() {
+    let builder = TemplateBuilder::new()
+        .with_alias("x", "uint:hex");
+    assert_eq!(format(builder, "{self|x()}", 255), "ff");
+    let builder = TemplateBuilder::new()
+        .with_alias("str", "to_string");
+    assert_eq!(format(builder, "{self|str()}", 255), "255");
+    let builder = TemplateBuilder::new()
+        .with_alias("x", "uint:unknown");
+    assert_eq!(build_error(builder, "{self}"), "Function with name `uint:unknown` not found");
+}
+
+#[test]
+fn test_overwrite_policy() {
+    let builder = TemplateBuilder::new()
+        .with_function(Upper);
+    assert_eq!(format(builder, "{self|uint:hex()}", 255), "FF");
+    let builder = TemplateBuilder::new()
+        .with_overwrite_policy(OverwritePolicy::Replace)
+        .with_function(Upper);
+    assert_eq!(format(builder, "{self|uint:hex()}", 255), "FF");
+    let builder = TemplateBuilder::new()
+        .without_namespace("uint")
+        .with_function(Upper);
+    assert_eq!(format(builder, "{self|uint:hex()}", 255), "FF");
+    let builder = TemplateBuilder::new()
+        .with_overwrite_policy(OverwritePolicy::Keep)
+        .with_function(Upper);
+    assert_eq!(format(builder, "{self|uint:hex()}", 255), "ff");
+    let builder = TemplateBuilder::new()
+        .with_overwrite_policy(OverwritePolicy::Error)
+        .with_function(Upper);
+    assert_eq!(build_error(builder, "{self}"), "Function `uint:hex` is already registered");
+    let builder = TemplateBuilder::new()
+        .with_overwrite_policy(OverwritePolicy::Error)
+        .with_alias("hex", "uint:hex");
+    assert_eq!(build_error(builder, "{self}"), "Function `hex` is already registered");
+    let builder = TemplateBuilder::new_empty()
+        .with_overwrite_policy(OverwritePolicy::Error)
+        .with_module(functions::uint::module())
+        .with_module(functions::int::module())
+        .with_module(functions::generic::module());
+    assert_eq!(format(builder, "{self|hex()}", 255), "ff");
+}
+
+#[test]
+fn test_registry_reports_registration_error() {
+    let builder = TemplateBuilder::new()
+        .with_overwrite_policy(OverwritePolicy::Error)
+        .with_function(Upper);
+    let error = TemplateRegistryBuilder::new(builder)
+        .with_template("a", "{self}")
+        .build()
+        .err()
+        .map(|error| format!("{}", error));
+    assert_eq!(error, Some("Function `uint:hex` is already registered".to_string()));
+}
@@ -0,0 +1,127 @@
+use oneline_template::template::{Template, TemplateBuilder};
+use oneline_template::function_executor::*;
+use serde::Serialize;
+
+fn assert_ok<T>(format: &str, value: &T, expected: &str)
+    where T: Serialize
+{
+    let template = Template::parse(format).expect("Unable to create template");
+    let value = template.serialize(value).expect("Unable to get value formatted by template");
+    assert_eq!(value, expected);
+}
+
+fn assert_err<T>(format: &str, value: &T, expected: &str)
+    where T: Serialize
+{
+    let template = Template::parse(format).expect("Unable to create template");
+    let error = template.serialize(value).expect_err("Unable to get value formatted by template");
+    let error = format!("{}", error);
+    assert_eq!(error, expected);
+}
+
+fn assert_template_err(format: &str, expected: &str) {
+    let error = Template::parse(format).expect_err("Template must not be created");
+    let error = format!("{}", error);
+    assert_eq!(error, expected);
+}
+
+#[derive(serde_derive::Serialize)]
+struct User {
+    id: Option<u32>,
+    name: Option<&'static str>,
+    nickname: Option<&'static str>,
+    login: &'static str,
+}
+
+struct Context;
+
+impl FunctionExecutor for Context {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("apply")
+            .with_argument(FunctionArgument::function())
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        return Ok(value);
+    }
+}
+
+#[test]
+fn test_option_map() {
+    let user = User {id: Some(255), name: None, nickname: None, login: "root"};
+    assert_ok("{self.id|option:map(uint:hex())|unwrap_or('-')}", &user, "ff");
+    assert_ok("{self.id|option:map(uint:add(1uint))|unwrap_or(0uint)|to_string()}", &user, "256");
+    assert_ok("{self.name|option:map(string:upper())|unwrap_or('-')}", &user, "-");
+    assert_ok("{self|option:map(option:is_some())|unwrap_or(false)|to_string()}", &Some(Some(10u32)), "true");
+    assert_ok("{self|option:map(option:is_some())|unwrap_or(false)|to_string()}", &None::<Option<u32>>, "false");
+    assert_err("{self.login|option:map(string:upper())}", &user, "Trying to cast String(\"root\") as option");
+}
+
+#[test]
+fn test_option_or() {
+    let user = User {id: None, name: None, nickname: Some("nick"), login: "root"};
+    assert_ok("{self.name|option:or(self.nickname)|unwrap_or('anonymous')}", &user, "nick");
+    assert_ok("{self.name|option:or(self.login)|unwrap_or('anonymous')}", &user, "root");
+    assert_ok("{self.name|option:or(self.missing)|unwrap_or('anonymous')}", &user, "anonymous");
+    let user = User {id: None, name: Some("name"), nickname: Some("nick"), login: "root"};
+    assert_ok("{self.name|option:or(self.nickname)|unwrap_or('anonymous')}", &user, "name");
+}
+
+#[test]
+fn test_option_is_some_and_unwrap_or_empty() {
+    let user = User {id: Some(7), name: None, nickname: None, login: "root"};
+    assert_ok("{self.id|option:is_some()|to_string()}", &user, "true");
+    assert_ok("{self.name|option:is_some()|to_string()}", &user, "false");
+    assert_ok("[{self.name|option:unwrap_or_empty()}]", &user, "[]");
+    assert_ok("[{self.id|option:unwrap_or_empty()|to_string()}]", &user, "[7]");
+}
+
+#[test]
+fn test_generic_scalar_unwrap_or() {
+    assert_ok("{self|unwrap_or(3uint)|to_string()}", &None::<u32>, "3");
+    assert_ok("{self|unwrap_or(3uint)|to_string()}", &Some(5u32), "5");
+    assert_ok("{self|unwrap_or(-3int)|to_string()}", &None::<i32>, "-3");
+    assert_ok("{self|unwrap_or(0.5float)|to_string()}", &None::<f64>, "0.5");
+    assert_ok("{self|uint:unwrap_or(3uint)|hex()}", &Some(255u32), "ff");
+}
+
+#[test]
+fn test_nested_arguments_are_validated() {
+    assert_template_err("{self|option:map(uint:unknown())}", "Function with name `uint:unknown` not found");
+    assert_template_err("{self|option:map(uint:add('1'))}", "Argument with index `0` at function `uint:add` has wrong type");
+    assert_template_err("{self|option:map(self.name)}", "Argument with index `0` at function `option:map` has wrong type");
+    assert_template_err("{self|option:or(uint:hex())}", "Argument with index `0` at function `option:or` has wrong type");
+    assert_template_err("{self|uint:pad(self.width)}", "Argument with index `0` at function `uint:pad` has wrong type");
+}
+
+#[test]
+fn test_option_output_is_validated() {
+    assert_template_err("{self|option:map(uint:hex())|string:upper()}", "Function `string:upper` does not accept input of type `Option<any>`");
+    assert_template_err("{self|option:map(uint:hex())|uint:to_string()}", "Function `uint:to_string` does not accept input of type `Option<any>`");
+    assert_template_err("{self|string:substr(0uint, 1uint)|string:upper()}", "Function `string:upper` does not accept input of type `Option<string>`");
+    assert_ok("{self|option:map(uint:hex())|string:unwrap_or('-')|string:upper()}", &Some(255u32), "FF");
+    assert_ok("{self|string:substr(0uint, 1uint)|option:is_some()|to_string()}", &"ab", "true");
+}
+
+#[test]
+fn test_function_argument_requires_context() {
+    let template = TemplateBuilder::new()
+        .with_function(Context)
+        .build("{self|apply(uint:hex())}")
+        .expect("Unable to create template");
+    let value = template.serialize(&"abc").expect("Unable to format");
+    assert_eq!(value, "abc");
+}
+
+#[test]
+fn test_bind_waits_for_field_arguments() {
+    #[derive(serde_derive::Serialize)]
+    struct Name {
+        name: Option<&'static str>,
+    }
+    let template = Template::parse("{self.name|option:or(self.nickname)|unwrap_or('-')}").expect("Unable to create template");
+    let bound = template.bind(&Name {name: None}).expect("Unable to bind template");
+    let user = User {id: None, name: None, nickname: Some("nick"), login: "root"};
+    let value = bound.serialize(&user).expect("Unable to format");
+    assert_eq!(value, "nick");
+}
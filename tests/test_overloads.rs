@@ -0,0 +1,125 @@
+use oneline_template::template::{Template, TemplateBuilder};
+use oneline_template::function_executor::*;
+use serde::Serialize;
+
+fn assert_ok<T>(format: &str, value: &T, expected: &str)
+    where T: Serialize
+{
+    let template = Template::parse(format).expect("Unable to create template");
+    let value = template.serialize(value).expect("Unable to get value formatted by template");
+    assert_eq!(value, expected);
+}
+
+fn assert_err<T>(format: &str, value: &T, expected: &str)
+    where T: Serialize
+{
+    let template = Template::parse(format).expect("Unable to create template");
+    let error = template.serialize(value).expect_err("Unable to get value formatted by template");
+    let error = format!("{}", error);
+    assert_eq!(error, expected);
+}
+
+fn assert_template_err(format: &str, expected: &str) {
+    let error = Template::parse(format).expect_err("Template must not be created");
+    let error = format!("{}", error);
+    assert_eq!(error, expected);
+}
+
+struct Repeat;
+
+impl FunctionExecutor for Repeat {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("twice")
+            .with_input_type(ValueType::String)
+            .with_output_type(ValueType::String)
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_string()?;
+        let value = Value::String(value.repeat(2));
+        return Ok(value);
+    }
+}
+
+struct Double;
+
+impl FunctionExecutor for Double {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("twice")
+            .with_input_type(ValueType::UInt)
+            .with_output_type(ValueType::UInt)
+    }
+
+    fn call(&self, value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        let value = value.as_uint()?;
+        let value = Value::UInt(value * 2);
+        return Ok(value);
+    }
+}
+
+#[test]
+fn test_generic_to_string() {
+    assert_ok("{self|to_string()}", &true, "true");
+    assert_ok("{self|to_string()}", &-5i32, "-5");
+    assert_ok("{self|to_string()}", &5u32, "5");
+    assert_ok("{self|to_string()}", &0.5f64, "0.5");
+    assert_ok("{self|to_string()}", &"abc", "abc");
+    assert_ok("{self|to_string()}", &'x', "x");
+    assert_err("{self|to_string()}", &vec![1u32], "Function `to_string` has no overload for input type `Seq<uint>`");
+}
+
+#[test]
+fn test_generic_unwrap_or() {
+    let value: Option<String> = None;
+    assert_ok("{self|unwrap_or('--')}", &value, "--");
+    let value = Some("abc".to_string());
+    assert_ok("{self|unwrap_or('--')}", &value, "abc");
+    let value: Option<bool> = None;
+    assert_ok("{self|unwrap_or(true)|to_string()}", &value, "true");
+}
+
+#[test]
+fn test_generic_arithmetic() {
+    assert_ok("{self|add(1uint)}", &5u32, "6");
+    assert_ok("{self|add(-1int)}", &5i32, "4");
+    assert_ok("{self|mul(2float)|to_string()}", &1.25f64, "2.5");
+    assert_ok("{self|div(1000uint)|hex()}", &255000u32, "ff");
+    assert_err("{self|add(1uint)}", &5i32, "Trying to cast Int(5) as uint");
+}
+
+#[test]
+fn test_overload_resolution_when_template_is_built() {
+    assert_template_err("{self|uint:hex()|string:split(',')|to_string()}", "Function `to_string` does not accept input of type `Seq<string>`");
+    assert_template_err("{self|string:split(',')|hex()}", "Function `hex` does not accept input of type `Seq<string>`");
+    assert_template_err("{self|add('1')}", "Argument with index `0` at function `add` has wrong type");
+    assert_template_err("{self|add(1uint, 2uint)}", "Into function `add` passed arguments with wrong length");
+    assert_template_err("{self|string:lower()|uint:hex()}", "Function `uint:hex` does not accept input of type `string`");
+    assert_template_err("{self|uint:to_string()|uint:to_string()}", "Function `uint:to_string` does not accept input of type `string`");
+    assert_ok("{self|uint:hex()|string:upper()}", &255u32, "FF");
+    assert_ok("{self|uint:add(1uint)|hex()}", &9u32, "a");
+    assert_ok("{self|string:split(',')|len()}", &"a,b", "2");
+}
+
+#[test]
+fn test_user_overloads() {
+    let template = TemplateBuilder::new()
+        .with_function(Repeat)
+        .with_function(Double)
+        .build("{self.name|twice()}/{self.id|twice()}")
+        .expect("Unable to create template");
+    #[derive(serde_derive::Serialize)]
+    struct Item {
+        name: &'static str,
+        id: u32,
+    }
+    let value = template.serialize(&Item {name: "ab", id: 21}).expect("Unable to format");
+    assert_eq!(value, "abab/42");
+    let value = TemplateBuilder::new()
+        .with_function(Repeat)
+        .with_function(Double)
+        .build("{self|uint:hex()|twice()|twice()}")
+        .expect("Unable to create template")
+        .serialize(&10u32)
+        .expect("Unable to format");
+    assert_eq!(value, "aaaa");
+}
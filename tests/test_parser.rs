@@ -134,13 +134,13 @@ fn test_parser_err_process_2() {
 
 #[test]
 fn test_parser_err_process_3() {
-    assert_template_err("{self.second.third.str_value | uint:to_string() | uint:to_string}", "Expected token `(` in `}`");
-    assert_template_err("{self.second.third.str_value | uint:to_string() | uint:to_string }", "Expected token `(` in `}`");
-    assert_template_err("{self.second.third.str_value | uint:to_string() | uint:to_string( }", "Invalid argument `` in input `}`");
-    assert_template_err("{self.second.third.str_value | uint:to_string() | uint:to_string ( }", "Invalid argument `` in input `}`");
-    assert_template_ok("{self.second.third.str_value | uint:to_string() | uint:to_string()}");
-    assert_template_ok("{self.second.third.str_value | uint:to_string() | uint:to_string () }");
-    assert_template_ok("{self.second.third.str_value | uint:to_string() | uint:to_string ( ) }");
+    assert_template_err("{self.second.third.str_value | uint:to_string() | string:trim}", "Expected token `(` in `}`");
+    assert_template_err("{self.second.third.str_value | uint:to_string() | string:trim }", "Expected token `(` in `}`");
+    assert_template_err("{self.second.third.str_value | uint:to_string() | string:trim( }", "Invalid argument `` in input `}`");
+    assert_template_err("{self.second.third.str_value | uint:to_string() | string:trim ( }", "Invalid argument `` in input `}`");
+    assert_template_ok("{self.second.third.str_value | uint:to_string() | string:trim()}");
+    assert_template_ok("{self.second.third.str_value | uint:to_string() | string:trim () }");
+    assert_template_ok("{self.second.third.str_value | uint:to_string() | string:trim ( ) }");
 }
 
 #[test]
@@ -151,4 +151,8 @@ fn test_parser_argument() {
     assert_template_ok("{self.second.third.str_value | string:unwrap_or('default_\\'value') }");
     assert_template_ok("{self.second.third.str_value | bool:unwrap_or(true) }");
     assert_template_ok("{self.second.third.str_value | bool:unwrap_or(false) }");
+    assert_template_ok("{self.second.third.float_value | float:add(-1.5float) }");
+    assert_template_err("{self.second.third.float_value | float:add(1.5uint) }", "invalid digit found in string");
+    assert_template_err("{self.second.third.float_value | float:add(1.5.5float) }", "invalid float literal");
+    assert_template_err("{self.second.third.float_value | float:add(1int) }", "Argument with index `0` at function `float:add` has wrong type");
 }
\ No newline at end of file
@@ -0,0 +1,104 @@
+use oneline_template::template::Template;
+use regex::Regex;
+
+fn assert_glob(format: &str, expected: &str) {
+    let template = Template::parse(format).expect("Unable to create template");
+    assert_eq!(template.to_glob(), expected);
+}
+
+fn assert_regex(format: &str, expected: &str) {
+    let template = Template::parse(format).expect("Unable to create template");
+    assert_eq!(template.to_regex(), expected);
+}
+
+#[derive(serde_derive::Serialize)]
+struct FileId {
+    tenant: &'static str,
+    file_id: u32,
+}
+
+#[test]
+fn test_glob() {
+    assert_glob("directory/file_no_{ self.file_id }.json", "directory/file_no_*.json");
+    assert_glob("{self.tenant}/{self.file_id | uint:hex()}", "*/*");
+    assert_glob("{self.tenant}{self.file_id}.json", "*.json");
+    assert_glob("[draft]*{self.file_id}", "\\[draft\\]\\**");
+}
+
+#[test]
+fn test_regex() {
+    assert_regex("directory/file_no_{ self.file_id }.json", "^directory/file_no_(?P<file_id>.*?)\\.json$");
+    assert_regex("{self.file_id | uint:hex()}", "^(?P<file_id>[0-9a-f]+)$");
+    assert_regex("{self.file_id | uint:hex_fmt(8uint)}", "^(?P<file_id>[0-9a-f]{8,})$");
+    assert_regex("{self.file_id | uint:hex_fmt(0uint)}", "^(?P<file_id>[0-9a-f]+)$");
+    assert_regex("{self.file_id | uint:to_string() | string:trim()}", "^(?P<file_id>.*?)$");
+    assert_regex("{self.a.b}/{self.a.b}/{self}/{self.0}", "^(?P<a_b>.*?)/(?P<a_b_2>.*?)/(?P<self>.*?)/(?P<_0>.*?)$");
+}
+
+#[test]
+fn test_regex_matches_rendered_value() {
+    let template = Template::parse("{self.tenant}/{self.file_id | uint:hex_fmt(8uint)}.json").expect("Unable to create template");
+    let regex = Regex::new(&template.to_regex()).expect("Unable to compile regex");
+    let value = FileId {
+        tenant: "acme",
+        file_id: 255,
+    };
+    let path = template.serialize(&value).expect("Unable to format path");
+    let captures = regex.captures(&path).expect("Regex does not match rendered value");
+    assert_eq!(&captures["tenant"], "acme");
+    assert_eq!(&captures["file_id"], "000000ff");
+    assert!(!regex.is_match("acme/ff.json"));
+}
+
+#[test]
+fn test_uri_template_patterns() {
+    let template = Template::parse_uri_template("/users/{id}{?fields,sort}").expect("Unable to create template");
+    assert_eq!(template.to_glob(), "/users/*");
+    assert_eq!(template.to_regex(), "^/users/(?P<id>.*?)(?P<fields_sort>.*?)$");
+}
+
+#[derive(serde_derive::Serialize)]
+struct Tenant {
+    tenant: &'static str,
+}
+
+#[test]
+fn test_static_prefix() {
+    let template = Template::parse("reports/2024/{self.file_id}.json").expect("Unable to create template");
+    assert_eq!(template.static_prefix(), "reports/2024/");
+    assert_eq!(template.static_segments(), vec!["reports", "2024"]);
+    let template = Template::parse("reports/2024/report_{self.file_id}.json").expect("Unable to create template");
+    assert_eq!(template.static_prefix(), "reports/2024/report_");
+    assert_eq!(template.static_segments(), vec!["reports", "2024"]);
+    let template = Template::parse("report_{self.file_id}.json").expect("Unable to create template");
+    assert_eq!(template.static_prefix(), "report_");
+    assert_eq!(template.static_segments(), Vec::<String>::new());
+    let template = Template::parse("{self.tenant}/reports").expect("Unable to create template");
+    assert_eq!(template.static_prefix(), "");
+    assert_eq!(template.static_segments(), Vec::<String>::new());
+    let template = Template::parse("/{self.tenant}/reports").expect("Unable to create template");
+    assert_eq!(template.static_segments(), Vec::<String>::new());
+    let template = Template::parse("/reports//2024/{self.file_id}.json").expect("Unable to create template");
+    assert_eq!(template.static_segments(), vec!["reports", "2024"]);
+    let template = Template::parse("reports/index.json").expect("Unable to create template");
+    assert_eq!(template.static_segments(), vec!["reports", "index.json"]);
+}
+
+#[test]
+fn test_static_prefix_uri_template() {
+    let template = Template::parse_uri_template("/monthly reports/{id}").expect("Unable to create template");
+    assert_eq!(template.static_prefix(), "/monthly%20reports/");
+    assert_eq!(template.static_segments(), vec!["monthly%20reports"]);
+}
+
+#[test]
+fn test_static_prefix_with_partial_value() {
+    let template = Template::parse("{self.tenant}/reports/{self.file_id}.json").expect("Unable to create template");
+    let tenant = Tenant {
+        tenant: "acme",
+    };
+    let static_prefix = template.static_prefix_with(&tenant).expect("Unable to bind template");
+    assert_eq!(static_prefix, "acme/reports/");
+    let bound = template.bind(&tenant).expect("Unable to bind template");
+    assert_eq!(bound.static_segments(), vec!["acme", "reports"]);
+}
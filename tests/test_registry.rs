@@ -0,0 +1,70 @@
+use oneline_template::template::{TemplateBuilder, TemplateRegistryBuilder, Template};
+
+#[derive(serde_derive::Serialize)]
+struct Report {
+    tenant: &'static str,
+    date: &'static str,
+    id: u32,
+}
+
+fn create_report() -> Report {
+    return Report {
+        tenant: "acme",
+        date: "2024-05-17",
+        id: 255,
+    }
+}
+
+fn assert_registry_err(registry_builder: TemplateRegistryBuilder, expected: &str) {
+    let error = registry_builder.build().unwrap_err();
+    assert_eq!(format!("{}", error), expected);
+}
+
+#[test]
+fn test_registry_render() {
+    let registry = TemplateRegistryBuilder::new(TemplateBuilder::new())
+        .with_template("tenant_prefix", "{self.tenant}/{self.date | string:substr(0uint, 4uint) | string:unwrap_or('----')}/")
+        .with_template("report", "{> tenant_prefix}reports/{ > file_name }")
+        .with_template("file_name", "{self.id | uint:hex()}.json")
+        .build()
+        .expect("Unable to create template registry");
+    let report = create_report();
+    assert_eq!(registry.render("report", &report).expect("Unable to render"), "acme/2024/reports/ff.json");
+    assert_eq!(registry.render("tenant_prefix", &report).expect("Unable to render"), "acme/2024/");
+    let template = registry.get("report").expect("Template not found");
+    assert_eq!(template.static_prefix(), "");
+    assert_eq!(template.to_glob(), "*/*/reports/*.json");
+    let error = registry.render("unknown", &report).unwrap_err();
+    assert_eq!(format!("{}", error), "Template `unknown` not found");
+    let mut template_names: Vec<_> = registry.template_names().collect();
+    template_names.sort();
+    assert_eq!(template_names, vec!["file_name", "report", "tenant_prefix"]);
+}
+
+#[test]
+fn test_registry_errors() {
+    let registry_builder = TemplateRegistryBuilder::new(TemplateBuilder::new())
+        .with_template("report", "{> tenant_prefix}reports/{self.id}.json");
+    assert_registry_err(registry_builder, "Template `report`: Template with name `tenant_prefix` not found");
+    let registry_builder = TemplateRegistryBuilder::new(TemplateBuilder::new())
+        .with_template("a", "a/{> b}")
+        .with_template("b", "b/{> c}")
+        .with_template("c", "c/{> a}");
+    assert_registry_err(registry_builder, "Template `a`: Templates include each other: `a -> b -> c -> a`");
+    let registry_builder = TemplateRegistryBuilder::new(TemplateBuilder::new())
+        .with_template("self_include", "{> self_include}");
+    assert_registry_err(registry_builder, "Template `self_include`: Templates include each other: `self_include -> self_include`");
+    let registry_builder = TemplateRegistryBuilder::new(TemplateBuilder::new())
+        .with_template("prefix", "{self.id | uint:unknown()}/")
+        .with_template("report", "{> prefix}{self.id}");
+    assert_registry_err(registry_builder, "Template `prefix`: Function with name `uint:unknown` not found");
+    let registry_builder = TemplateRegistryBuilder::new(TemplateBuilder::new())
+        .with_template("report", "{> }");
+    assert_registry_err(registry_builder, "Template `report`: Wrong template name");
+}
+
+#[test]
+fn test_include_without_registry() {
+    let error = Template::parse("{> tenant_prefix}{self.id}").unwrap_err();
+    assert_eq!(format!("{}", error), "Template with name `tenant_prefix` not found");
+}
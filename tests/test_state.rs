@@ -0,0 +1,116 @@
+use oneline_template::template::{Template, TemplateBuilder, TemplateContext};
+use oneline_template::function_executor::*;
+use serde_derive::Serialize;
+
+#[derive(Serialize)]
+struct Item {
+    a: String,
+    b: String,
+}
+
+fn item(a: &str, b: &str) -> Item {
+    return Item {
+        a: a.to_string(),
+        b: b.to_string(),
+    };
+}
+
+fn render_batch<T: serde::Serialize>(format: &str, values: &[T]) -> Vec<String> {
+    let template = Template::parse(format).expect("Unable to create template");
+    return template.render_batch(values).expect("Unable to format values");
+}
+
+#[test]
+fn test_counter() {
+    let result = render_batch("{self | seq:counter() | uint:pad(3uint)}-{self}", &["a", "b", "c"]);
+    assert_eq!(result, vec!["001-a", "002-b", "003-c"]);
+    let template = Template::parse("{self | seq:counter() | uint:to_string()}").expect("Unable to create template");
+    assert_eq!(template.serialize(&"a").expect("Unable to format value"), "1");
+    assert_eq!(template.serialize(&"a").expect("Unable to format value"), "1");
+    assert_eq!(template.render_batch(["a", "b"].iter()).expect("Unable to format values"), vec!["1", "2"]);
+    assert_eq!(template.render_batch(["a", "b"].iter()).expect("Unable to format values"), vec!["1", "2"]);
+    let result = template.render_batch(Vec::<String>::new()).expect("Unable to format values");
+    assert!(result.is_empty());
+}
+
+#[test]
+fn test_counter_per_placeholder() {
+    let result = render_batch(
+        "{self.a | seq:counter() | uint:to_string()}/{self.b | seq:counter() | uint:to_string()}/{self.a | seq:counter() | uint:to_string()}",
+        &[item("x", "y"), item("x", "y")],
+    );
+    assert_eq!(result, vec!["1/1/1", "2/2/2"]);
+}
+
+#[test]
+fn test_dedupe() {
+    let result = render_batch("{self | string:dedupe()}", &["a", "a", "a-1", "a", "b", "b"]);
+    assert_eq!(result, vec!["a", "a-1", "a-1-1", "a-2", "b", "b-1"]);
+    let result = render_batch("{self.a | string:dedupe()}/{self.b | string:dedupe()}", &[item("x", "x"), item("x", "x")]);
+    assert_eq!(result, vec!["x/x", "x-1/x-1"]);
+    let result = render_batch("{self | string:dedupe()}/{self | string:dedupe()}", &["x", "x"]);
+    assert_eq!(result, vec!["x/x", "x-1/x-1"]);
+    let template = Template::parse("{self | string:dedupe()}").expect("Unable to create template");
+    assert_eq!(template.serialize(&"a").expect("Unable to format value"), "a");
+    assert_eq!(template.serialize(&"a").expect("Unable to format value"), "a");
+}
+
+#[test]
+fn test_batch_error() {
+    let template = Template::parse("{self | seq:counter() | uint:sub(2uint) | uint:to_string()}").expect("Unable to create template");
+    assert!(template.render_batch([1u32, 2u32]).is_err());
+}
+
+struct CallNumber;
+
+impl FunctionExecutor for CallNumber {
+    fn schema(&self) -> FunctionSchema {
+        FunctionSchema::new("call_number")
+            .with_output_type(ValueType::String)
+    }
+
+    fn call(&self, _value: Value, _arguments: &[Value]) -> Result<Value, FunctionError> {
+        return Err(FunctionError::msg("Function `call_number` requires function context"));
+    }
+
+    fn call_with_context(&self, _value: Value, _arguments: &[Value], context: &FunctionContext) -> Result<Value, FunctionError> {
+        let mut state = context.get_render_state();
+        let number = match state.remove("call_number") {
+            Some(number) => *number.as_uint()? + 1,
+            None => 1,
+        };
+        let _ = state.set("call_number", Value::UInt(number));
+        let prefix = match context.get_variable("prefix") {
+            Some(prefix) => prefix.as_string()?.clone(),
+            None => String::new(),
+        };
+        return Ok(Value::String(format!("{}{}", prefix, number)));
+    }
+}
+
+#[test]
+fn test_render_state() {
+    let template = TemplateBuilder::new()
+        .with_function(CallNumber)
+        .build("{self | call_number()}{self | call_number()}")
+        .expect("Unable to create template");
+    assert_eq!(template.render_batch(["a", "b"]).expect("Unable to format values"), vec!["12", "12"]);
+    let context = TemplateContext::new()
+        .with_variable("prefix", "#");
+    let result = template
+        .render_batch_with_context(["a", "b"], &context)
+        .expect("Unable to format values");
+    assert_eq!(result, vec!["#1#2", "#1#2"]);
+}
+
+#[test]
+fn test_function_state() {
+    let mut state = FunctionState::new();
+    assert!(!state.contains("key"));
+    assert!(state.set("key", Value::UInt(1)).is_none());
+    assert!(state.contains("key"));
+    assert!(matches!(state.get("key"), Some(Value::UInt(1))));
+    assert!(matches!(state.set("key", Value::UInt(2)), Some(Value::UInt(1))));
+    assert!(matches!(state.remove("key"), Some(Value::UInt(2))));
+    assert!(state.get("key").is_none());
+}
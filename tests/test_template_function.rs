@@ -0,0 +1,71 @@
+#![cfg(feature = "macros")]
+use oneline_template::template::TemplateBuilder;
+use oneline_template::function_executor::*;
+use oneline_template::template_function;
+
+#[template_function(name = "uint:neg")]
+fn neg(value: u128) -> u128 {
+    return !value;
+}
+
+#[template_function(name = "string:surround", struct_name = "Surround")]
+fn surround_with(value: String, prefix: String, suffix: String, count: usize) -> String {
+    let suffix = suffix.repeat(count);
+    return format!("{}{}{}", prefix, value, suffix);
+}
+
+#[template_function(name = "string:parse_int")]
+fn parse_int(value: String) -> Result<i64, std::num::ParseIntError> {
+    return value.trim().parse();
+}
+
+#[template_function(name = "option:or_zero")]
+fn or_zero(value: Option<u8>) -> u8 {
+    return value.unwrap_or(0);
+}
+
+fn builder() -> TemplateBuilder {
+    return TemplateBuilder::new()
+        .with_function(Neg)
+        .with_function(Surround)
+        .with_function(ParseInt)
+        .with_function(OrZero);
+}
+
+fn serialize<T>(format: &str, value: &T) -> Result<String, String>
+    where T: serde::Serialize
+{
+    let template = builder().build(format).expect("Unable to create template");
+    return template.serialize(value).map_err(|error| format!("{}", error));
+}
+
+#[test]
+fn test_generated_schema() {
+    let schema = Surround.schema();
+    assert_eq!(schema.get_function_name(), "string:surround");
+    assert_eq!(schema.get_input_type(), Some(&ValueType::String));
+    assert_eq!(schema.get_output_type(), Some(&ValueType::String));
+    assert_eq!(schema.get_arguments().len(), 3);
+    let schema = OrZero.schema();
+    assert_eq!(schema.get_input_type(), Some(&ValueType::option(ValueType::UInt)));
+    assert_eq!(neg(0), u128::MAX);
+}
+
+#[test]
+fn test_generated_function() {
+    assert_eq!(serialize("{self|uint:neg()|hex()}", &1u32), Ok("fffffffffffffffffffffffffffffffe".to_string()));
+    assert_eq!(serialize("{self|string:surround('<', '>', 2uint)}", &"a"), Ok("<a>>".to_string()));
+    assert_eq!(serialize("{self|string:parse_int()|to_string()}", &" -12 "), Ok("-12".to_string()));
+    assert_eq!(serialize("{self|string:parse_int()|to_string()}", &"x"), Err("invalid digit found in string".to_string()));
+    assert_eq!(serialize("{self|option:or_zero()|to_string()}", &None::<u32>), Ok("0".to_string()));
+    assert_eq!(serialize("{self|option:or_zero()|to_string()}", &Some(300u32)), Err("Unable to convert uint 300 into u8".to_string()));
+}
+
+#[test]
+fn test_generated_arguments_are_validated() {
+    let error = match builder().build("{self|string:surround('<')}") {
+        Ok(..) => panic!("Template must not be created"),
+        Err(error) => format!("{}", error),
+    };
+    assert_eq!(error, "Into function `string:surround` passed arguments with wrong length");
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "macros")]
+use oneline_template::template;
+use oneline_template::template::LazyTemplate;
+
+#[derive(serde_derive::Serialize)]
+struct File {
+    id: u32,
+    name: &'static str,
+}
+
+thread_local! {
+    static FILE_PATH: LazyTemplate = const { template!("dir/{self.id | uint:pad(4uint)}/{self.name | string:slug()}.json") };
+}
+
+#[test]
+fn test_template_macro() {
+    let template = template!("dir/{self.id}.json");
+    assert_eq!(template.get_format(), "dir/{self.id}.json");
+    let path = template.serialize(&File {id: 7, name: "a"}).expect("Unable to format path");
+    assert_eq!(path, "dir/7.json");
+    let path = FILE_PATH.with(|template| {
+        return template.serialize(&File {id: 12, name: "Hello World"}).expect("Unable to format path");
+    });
+    assert_eq!(path, "dir/0012/hello-world.json");
+}
+
+#[test]
+#[should_panic(expected = "Unable to build template `{self | uint:unknown()}`: Function with name `uint:unknown` not found")]
+fn test_template_macro_with_unknown_function() {
+    let template = template!("{self | uint:unknown()}");
+    let _ = template.serialize(&1u32);
+}
+
+#[test]
+fn test_lazy_template() {
+    let template = LazyTemplate::new("{self | string:upper()}");
+    assert_eq!(template.get().serialize(&"abc").expect("Unable to format"), "ABC");
+}
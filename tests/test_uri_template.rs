@@ -0,0 +1,212 @@
+use oneline_template::template::Template;
+use std::collections::BTreeMap;
+
+fn assert_ok(format: &str, expected: &str) {
+    let template = Template::parse_uri_template(format).expect("Unable to create template");
+    let value = template.serialize(&create_object()).expect("Unable to get value formatted by template");
+    assert_eq!(value, expected);
+}
+
+fn assert_template_err(format: &str, expected: &str) {
+    let template = Template::parse_uri_template(format);
+    let error = template.unwrap_err();
+    let error = format!("{}", error);
+    assert_eq!(error, expected);
+}
+
+#[derive(serde_derive::Serialize)]
+struct Variables {
+    count: Vec<&'static str>,
+    dom: Vec<&'static str>,
+    dub: &'static str,
+    hello: &'static str,
+    half: &'static str,
+    var: &'static str,
+    who: &'static str,
+    base: &'static str,
+    path: &'static str,
+    list: Vec<&'static str>,
+    keys: BTreeMap<&'static str, &'static str>,
+    v: u32,
+    x: u32,
+    y: u32,
+    empty: &'static str,
+    empty_keys: BTreeMap<&'static str, &'static str>,
+    undef: Option<&'static str>,
+    nested: Nested,
+}
+
+#[derive(serde_derive::Serialize)]
+struct Nested {
+    id: u32,
+}
+
+fn create_object() -> Variables {
+    let mut keys = BTreeMap::new();
+    let _ = keys.insert("semi", ";");
+    let _ = keys.insert("dot", ".");
+    let _ = keys.insert("comma", ",");
+    return Variables {
+        count: vec!["one", "two", "three"],
+        dom: vec!["example", "com"],
+        dub: "me/too",
+        hello: "Hello World!",
+        half: "50%",
+        var: "value",
+        who: "fred",
+        base: "http://example.com/home/",
+        path: "/foo/bar",
+        list: vec!["red", "green", "blue"],
+        keys,
+        v: 6,
+        x: 1024,
+        y: 768,
+        empty: "",
+        empty_keys: BTreeMap::new(),
+        undef: None,
+        nested: Nested {
+            id: 42,
+        },
+    }
+}
+
+#[test]
+fn test_uri_simple() {
+    assert_ok("{var}", "value");
+    assert_ok("{hello}", "Hello%20World%21");
+    assert_ok("{half}", "50%25");
+    assert_ok("O{empty}X", "OX");
+    assert_ok("O{undef}X", "OX");
+    assert_ok("{x,y}", "1024,768");
+    assert_ok("{x,hello,y}", "1024,Hello%20World%21,768");
+    assert_ok("?{x,empty}", "?1024,");
+    assert_ok("?{x,undef}", "?1024");
+    assert_ok("?{undef,y}", "?768");
+    assert_ok("{var:3}", "val");
+    assert_ok("{var:30}", "value");
+    assert_ok("{list}", "red,green,blue");
+    assert_ok("{list*}", "red,green,blue");
+    assert_ok("{keys}", "comma,%2C,dot,.,semi,%3B");
+    assert_ok("{keys*}", "comma=%2C,dot=.,semi=%3B");
+}
+
+#[test]
+fn test_uri_reserved() {
+    assert_ok("{+var}", "value");
+    assert_ok("{+hello}", "Hello%20World!");
+    assert_ok("{+half}", "50%25");
+    assert_ok("{base}index", "http%3A%2F%2Fexample.com%2Fhome%2Findex");
+    assert_ok("{+base}index", "http://example.com/home/index");
+    assert_ok("O{+empty}X", "OX");
+    assert_ok("{+path}/here", "/foo/bar/here");
+    assert_ok("here?ref={+path}", "here?ref=/foo/bar");
+    assert_ok("up{+path}{var}/here", "up/foo/barvalue/here");
+    assert_ok("{+x,hello,y}", "1024,Hello%20World!,768");
+    assert_ok("{+path:6}/here", "/foo/b/here");
+    assert_ok("{+list*}", "red,green,blue");
+    assert_ok("{+keys*}", "comma=,,dot=.,semi=;");
+}
+
+#[test]
+fn test_uri_fragment() {
+    assert_ok("{#var}", "#value");
+    assert_ok("{#hello}", "#Hello%20World!");
+    assert_ok("{#half}", "#50%25");
+    assert_ok("foo{#empty}", "foo#");
+    assert_ok("foo{#undef}", "foo");
+    assert_ok("{#x,hello,y}", "#1024,Hello%20World!,768");
+    assert_ok("{#path,x}/here", "#/foo/bar,1024/here");
+    assert_ok("{#path:2}", "#/f");
+    assert_ok("{#list}", "#red,green,blue");
+    assert_ok("{#keys}", "#comma,,,dot,.,semi,;");
+}
+
+#[test]
+fn test_uri_label() {
+    assert_ok("{.who}", ".fred");
+    assert_ok("{.who,who}", ".fred.fred");
+    assert_ok("{.half,who}", ".50%25.fred");
+    assert_ok("www{.dom*}", "www.example.com");
+    assert_ok("X{.var}", "X.value");
+    assert_ok("X{.empty}", "X.");
+    assert_ok("X{.undef}", "X");
+    assert_ok("X{.var:3}", "X.val");
+    assert_ok("X{.list}", "X.red,green,blue");
+    assert_ok("X{.list*}", "X.red.green.blue");
+    assert_ok("X{.empty_keys}", "X");
+}
+
+#[test]
+fn test_uri_path() {
+    assert_ok("{/who}", "/fred");
+    assert_ok("{/who,who}", "/fred/fred");
+    assert_ok("{/half,who}", "/50%25/fred");
+    assert_ok("{/who,dub}", "/fred/me%2Ftoo");
+    assert_ok("{/var}", "/value");
+    assert_ok("{/var,empty}", "/value/");
+    assert_ok("{/var,undef}", "/value");
+    assert_ok("{/var,x}/here", "/value/1024/here");
+    assert_ok("{/var:1,var}", "/v/value");
+    assert_ok("{/list}", "/red,green,blue");
+    assert_ok("{/list*}", "/red/green/blue");
+    assert_ok("{/list*,path:4}", "/red/green/blue/%2Ffoo");
+}
+
+#[test]
+fn test_uri_path_parameter() {
+    assert_ok("{;who}", ";who=fred");
+    assert_ok("{;half}", ";half=50%25");
+    assert_ok("{;empty}", ";empty");
+    assert_ok("{;v,empty,who}", ";v=6;empty;who=fred");
+    assert_ok("{;v,bar,who}", ";v=6;who=fred");
+    assert_ok("{;x,y}", ";x=1024;y=768");
+    assert_ok("{;x,y,empty}", ";x=1024;y=768;empty");
+    assert_ok("{;x,y,undef}", ";x=1024;y=768");
+    assert_ok("{;hello:5}", ";hello=Hello");
+    assert_ok("{;list}", ";list=red,green,blue");
+    assert_ok("{;list*}", ";list=red;list=green;list=blue");
+    assert_ok("{;keys*}", ";comma=%2C;dot=.;semi=%3B");
+}
+
+#[test]
+fn test_uri_query() {
+    assert_ok("{?who}", "?who=fred");
+    assert_ok("{?half}", "?half=50%25");
+    assert_ok("{?x,y}", "?x=1024&y=768");
+    assert_ok("{?x,y,empty}", "?x=1024&y=768&empty=");
+    assert_ok("{?x,y,undef}", "?x=1024&y=768");
+    assert_ok("{?var:3}", "?var=val");
+    assert_ok("{?list}", "?list=red,green,blue");
+    assert_ok("{?list*}", "?list=red&list=green&list=blue");
+    assert_ok("{?keys}", "?keys=comma,%2C,dot,.,semi,%3B");
+    assert_ok("{?keys*}", "?comma=%2C&dot=.&semi=%3B");
+    assert_ok("?fixed=yes{&x}", "?fixed=yes&x=1024");
+    assert_ok("{&x,y,empty}", "&x=1024&y=768&empty=");
+    assert_ok("{&var:3}", "&var=val");
+    assert_ok("{&list*}", "&list=red&list=green&list=blue");
+}
+
+#[test]
+fn test_uri_nested_path() {
+    assert_ok("/items/{nested.id}{?x}", "/items/42?x=1024");
+    assert_ok("/users/{who}{?list*}", "/users/fred?list=red&list=green&list=blue");
+}
+
+#[test]
+fn test_uri_literal_encoding() {
+    assert_ok("/dir name/{var}", "/dir%20name/value");
+}
+
+#[test]
+fn test_uri_err() {
+    assert_template_err("{}", "Wrong field name");
+    assert_template_err("{var", "Expected token `}` in ``");
+    assert_template_err("a}b", "Unexpected input `}b`");
+    assert_template_err("/{var}}", "Unexpected input `}`");
+    assert_template_err("{var:0}", "Wrong URI template variable modifier");
+    assert_template_err("{var:10000}", "Wrong URI template variable modifier");
+    assert_template_err("{var:}", "Wrong URI template variable modifier");
+    let template = Template::parse_uri_template("{list:3}").expect("Unable to create template");
+    let error = template.serialize(&create_object()).unwrap_err();
+    assert_eq!(format!("{}", error), "Prefix modifier is not applicable to composite value at path `list`");
+}